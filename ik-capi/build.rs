@@ -0,0 +1,23 @@
+use std::env;
+use std::path::Path;
+
+// the header is generated into OUT_DIR, `IK_CAPI_UPDATE_HEADER=1 cargo build -p ik-capi`
+// refreshes the committed include/ik.h as well
+fn main() {
+    let crate_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let out_dir = env::var("OUT_DIR").unwrap();
+    let config = cbindgen::Config::from_file(Path::new(&crate_dir).join("cbindgen.toml"))
+        .expect("read cbindgen.toml error");
+    let bindings = cbindgen::Builder::new()
+        .with_crate(&crate_dir)
+        .with_config(config)
+        .generate()
+        .expect("generate ik.h error");
+    bindings.write_to_file(Path::new(&out_dir).join("ik.h"));
+    if env::var_os("IK_CAPI_UPDATE_HEADER").is_some() {
+        bindings.write_to_file(Path::new(&crate_dir).join("include").join("ik.h"));
+    }
+    println!("cargo:rerun-if-changed=src/lib.rs");
+    println!("cargo:rerun-if-changed=cbindgen.toml");
+    println!("cargo:rerun-if-env-changed=IK_CAPI_UPDATE_HEADER");
+}
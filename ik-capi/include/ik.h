@@ -0,0 +1,136 @@
+#ifndef IK_H
+#define IK_H
+
+/* generated by cbindgen from ik-capi/src/lib.rs, do not edit */
+
+#include <stddef.h>
+#include <stdint.h>
+
+// Return code of every fallible function.
+typedef enum IkStatus {
+  IK_STATUS_OK = 0,
+  IK_STATUS_NULL_POINTER = 1,
+  IK_STATUS_INVALID_UTF8 = 2,
+  // the config file can not be loaded, or another one is loaded already
+  IK_STATUS_CONFIG = 3,
+  // `out_len` holds the number of tokens needed
+  IK_STATUS_BUFFER_TOO_SMALL = 4,
+  IK_STATUS_INVALID_MODE = 5,
+  IK_STATUS_PANIC = 6,
+} IkStatus;
+
+// Mirror of `LexemeType`, values are stable and only appended.
+typedef enum IkLexemeType {
+  IK_LEXEME_TYPE_UNKNOWN = 0,
+  IK_LEXEME_TYPE_ENGLISH = 1,
+  IK_LEXEME_TYPE_ARABIC = 2,
+  IK_LEXEME_TYPE_LETTER = 3,
+  IK_LEXEME_TYPE_CN_WORD = 4,
+  IK_LEXEME_TYPE_CN_CHAR = 5,
+  IK_LEXEME_TYPE_OTHER_CJK = 6,
+  IK_LEXEME_TYPE_CNUM = 7,
+  IK_LEXEME_TYPE_COUNT = 8,
+  IK_LEXEME_TYPE_CQUAN = 9,
+  IK_LEXEME_TYPE_DECIMAL = 10,
+  IK_LEXEME_TYPE_PERCENT = 11,
+  IK_LEXEME_TYPE_FRACTION = 12,
+  IK_LEXEME_TYPE_DATETIME = 13,
+  IK_LEXEME_TYPE_URL = 14,
+  IK_LEXEME_TYPE_EMAIL = 15,
+  IK_LEXEME_TYPE_IP = 16,
+  IK_LEXEME_TYPE_PHONE = 17,
+  IK_LEXEME_TYPE_EMOJI = 18,
+  IK_LEXEME_TYPE_SYMBOL = 19,
+  IK_LEXEME_TYPE_PINYIN = 20,
+  IK_LEXEME_TYPE_SYNONYM = 21,
+} IkLexemeType;
+
+// `IK_MODE_SEARCH` is the smart mode, `IK_MODE_INDEX` the max word mode.
+typedef enum IkMode {
+  IK_MODE_INDEX = 0,
+  IK_MODE_SEARCH = 1,
+} IkMode;
+
+// Opaque segmenter handle, thread safe.
+typedef struct IkSegmenter IkSegmenter;
+
+// One token, `text` is NULL unless the token comes from `ik_tokenize_alloc`.
+typedef struct IkToken {
+  // byte offset of the first byte in the input
+  size_t start_offset;
+  // byte offset after the last byte in the input
+  size_t end_offset;
+  enum IkLexemeType lexeme_type;
+  uint32_t position_increment;
+  uint32_t position_length;
+  // NUL terminated token text
+  const char *text;
+} IkToken;
+
+// Tokens owned by the library, release with `ik_tokens_free`.
+typedef struct IkTokens {
+  struct IkToken *tokens;
+  size_t len;
+} IkTokens;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+// Message of a status code, a static string that must not be freed, "unknown status" for
+// other values.
+const char *ik_status_message(int status);
+
+// Name of a lexeme type as in `LexemeType::as_str`: "CN_WORD", a static string, "unknown lexeme
+// type" for other values.
+const char *ik_lexeme_type_name(int lexeme_type);
+
+// Create a segmenter. `config_path` may be NULL for the bundled ik.yml, dictionaries are
+// process wide so every segmenter must use the same config.
+//
+// # Safety
+// `config_path` is NULL or a NUL terminated string, `out` is a valid pointer.
+enum IkStatus ik_segmenter_new(const char *config_path, struct IkSegmenter **out);
+
+// Destroy a segmenter, NULL is ignored.
+//
+// # Safety
+// `segmenter` comes from `ik_segmenter_new` and is not used afterwards.
+void ik_segmenter_free(struct IkSegmenter *segmenter);
+
+// Tokenize `text_len` bytes of UTF-8 `text` in `mode`, one of `IkMode`, into the caller
+// owned `tokens` buffer of `capacity` tokens, `out_len` receives the number of tokens.
+// When the buffer is too small `IK_STATUS_BUFFER_TOO_SMALL` is returned with the needed
+// number in `out_len`.
+//
+// # Safety
+// `text` points to `text_len` bytes, `tokens` to `capacity` tokens, `out_len` is valid.
+enum IkStatus ik_tokenize(const struct IkSegmenter *segmenter,
+                          const char *text,
+                          size_t text_len,
+                          int32_t mode,
+                          struct IkToken *tokens,
+                          size_t capacity,
+                          size_t *out_len);
+
+// Like `ik_tokenize`, but into tokens allocated by the library, with token texts.
+//
+// # Safety
+// `text` points to `text_len` bytes, `out` is valid, free the result with `ik_tokens_free`.
+enum IkStatus ik_tokenize_alloc(const struct IkSegmenter *segmenter,
+                                const char *text,
+                                size_t text_len,
+                                int32_t mode,
+                                struct IkTokens **out);
+
+// Free tokens from `ik_tokenize_alloc`, NULL is ignored.
+//
+// # Safety
+// `tokens` comes from `ik_tokenize_alloc` and is not used afterwards.
+void ik_tokens_free(struct IkTokens *tokens);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* IK_H */
@@ -0,0 +1,381 @@
+//! C API of ik-rs, the header `include/ik.h` is generated by cbindgen.
+//!
+//! Offsets of tokens are byte offsets into the UTF-8 input, so that C callers can
+//! slice the input directly. Token texts are only returned by `ik_tokenize_alloc`,
+//! they are regularized (lower case, half width) and may differ from the input slice.
+
+use std::ffi::{c_char, c_int, CStr, CString};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::path::Path;
+use std::{ptr, slice};
+
+use ik_rs::core::ik_segmenter::{IKSegmenter, TokenMode};
+use ik_rs::core::lexeme::{Lexeme, LexemeType};
+use ik_rs::dict::dictionary::{global_config_path, set_global_config};
+
+/// Opaque segmenter handle, thread safe.
+pub struct IkSegmenter {
+    inner: IKSegmenter,
+}
+
+/// Return code of every fallible function.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IkStatus {
+    Ok = 0,
+    NullPointer = 1,
+    InvalidUtf8 = 2,
+    /// the config file can not be loaded, or another one is loaded already
+    Config = 3,
+    /// `out_len` holds the number of tokens needed
+    BufferTooSmall = 4,
+    InvalidMode = 5,
+    Panic = 6,
+}
+
+/// `IK_MODE_SEARCH` is the smart mode, `IK_MODE_INDEX` the max word mode.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IkMode {
+    Index = 0,
+    Search = 1,
+}
+
+/// Mirror of `LexemeType`, values are stable and only appended.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IkLexemeType {
+    Unknown = 0,
+    English = 1,
+    Arabic = 2,
+    Letter = 3,
+    CnWord = 4,
+    CnChar = 5,
+    OtherCjk = 6,
+    Cnum = 7,
+    Count = 8,
+    Cquan = 9,
+    Decimal = 10,
+    Percent = 11,
+    Fraction = 12,
+    Datetime = 13,
+    Url = 14,
+    Email = 15,
+    Ip = 16,
+    Phone = 17,
+    Emoji = 18,
+    Symbol = 19,
+    Pinyin = 20,
+    Synonym = 21,
+}
+
+impl From<&LexemeType> for IkLexemeType {
+    fn from(lexeme_type: &LexemeType) -> Self {
+        match lexeme_type {
+            LexemeType::UNKNOWN => IkLexemeType::Unknown,
+            LexemeType::ENGLISH => IkLexemeType::English,
+            LexemeType::ARABIC => IkLexemeType::Arabic,
+            LexemeType::LETTER => IkLexemeType::Letter,
+            LexemeType::CNWORD => IkLexemeType::CnWord,
+            LexemeType::CNCHAR => IkLexemeType::CnChar,
+            LexemeType::OtherCJK => IkLexemeType::OtherCjk,
+            LexemeType::CNUM => IkLexemeType::Cnum,
+            LexemeType::COUNT => IkLexemeType::Count,
+            LexemeType::CQUAN => IkLexemeType::Cquan,
+            LexemeType::DECIMAL => IkLexemeType::Decimal,
+            LexemeType::PERCENT => IkLexemeType::Percent,
+            LexemeType::FRACTION => IkLexemeType::Fraction,
+            LexemeType::DATETIME => IkLexemeType::Datetime,
+            LexemeType::URL => IkLexemeType::Url,
+            LexemeType::EMAIL => IkLexemeType::Email,
+            LexemeType::IP => IkLexemeType::Ip,
+            LexemeType::PHONE => IkLexemeType::Phone,
+            LexemeType::EMOJI => IkLexemeType::Emoji,
+            LexemeType::SYMBOL => IkLexemeType::Symbol,
+            LexemeType::PINYIN => IkLexemeType::Pinyin,
+            LexemeType::SYNONYM => IkLexemeType::Synonym,
+        }
+    }
+}
+
+/// One token, `text` is NULL unless the token comes from `ik_tokenize_alloc`.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct IkToken {
+    /// byte offset of the first byte in the input
+    pub start_offset: usize,
+    /// byte offset after the last byte in the input
+    pub end_offset: usize,
+    pub lexeme_type: IkLexemeType,
+    pub position_increment: u32,
+    pub position_length: u32,
+    /// NUL terminated token text
+    pub text: *const c_char,
+}
+
+/// Tokens owned by the library, release with `ik_tokens_free`.
+#[repr(C)]
+pub struct IkTokens {
+    pub tokens: *mut IkToken,
+    pub len: usize,
+}
+
+// NUL terminated for C, not c"" literals which the pinned toolchain does not support
+const STATUS_MESSAGES: [&str; 7] = [
+    "ok\0",
+    "null pointer\0",
+    "input is not valid UTF-8\0",
+    "config can not be loaded\0",
+    "token buffer too small\0",
+    "invalid mode\0",
+    "internal panic\0",
+];
+
+// indexed by `IkLexemeType`
+const LEXEME_TYPE_NAMES: [&str; 22] = [
+    "UNKNOW\0",
+    "ENGLISH\0",
+    "ARABIC\0",
+    "LETTER\0",
+    "CN_WORD\0",
+    "CN_CHAR\0",
+    "OtherCjk\0",
+    "TYPE_CNUM\0",
+    "COUNT\0",
+    "TYPE_CQUAN\0",
+    "TYPE_DECIMAL\0",
+    "TYPE_PERCENT\0",
+    "TYPE_FRACTION\0",
+    "DATETIME\0",
+    "URL\0",
+    "EMAIL\0",
+    "IP\0",
+    "PHONE\0",
+    "EMOJI\0",
+    "SYMBOL\0",
+    "PINYIN\0",
+    "SYNONYM\0",
+];
+
+fn guard<F: FnOnce() -> IkStatus>(f: F) -> IkStatus {
+    catch_unwind(AssertUnwindSafe(f)).unwrap_or(IkStatus::Panic)
+}
+
+fn to_token(lexeme: &Lexeme, byte_offsets: &[usize], text: *const c_char) -> IkToken {
+    IkToken {
+        start_offset: byte_offsets[lexeme.begin_pos()],
+        end_offset: byte_offsets[lexeme.end_pos()],
+        lexeme_type: IkLexemeType::from(lexeme.lexeme_type()),
+        position_increment: lexeme.position_increment() as u32,
+        position_length: lexeme.position_length() as u32,
+        text,
+    }
+}
+
+// char offset -> byte offset, one more entry for the end of text
+fn byte_offsets(text: &str) -> Vec<usize> {
+    let mut offsets: Vec<usize> = text.char_indices().map(|(i, _)| i).collect();
+    offsets.push(text.len());
+    offsets
+}
+
+unsafe fn segment(
+    segmenter: *const IkSegmenter,
+    text: *const c_char,
+    text_len: usize,
+    mode: i32,
+) -> Result<(String, Vec<Lexeme>), IkStatus> {
+    if segmenter.is_null() || (text.is_null() && text_len > 0) {
+        return Err(IkStatus::NullPointer);
+    }
+    let bytes = if text_len == 0 {
+        &[]
+    } else {
+        slice::from_raw_parts(text as *const u8, text_len)
+    };
+    let input = std::str::from_utf8(bytes).map_err(|_| IkStatus::InvalidUtf8)?;
+    // an out of range enum from C would be undefined behavior, so the mode is a plain int
+    let mode = match mode {
+        m if m == IkMode::Index as i32 => TokenMode::INDEX,
+        m if m == IkMode::Search as i32 => TokenMode::SEARCH,
+        _ => return Err(IkStatus::InvalidMode),
+    };
+    let lexemes = (*segmenter).inner.tokenize(input, mode);
+    Ok((input.to_string(), lexemes))
+}
+
+/// Message of a status code, a static string that must not be freed, "unknown status" for
+/// other values.
+#[no_mangle]
+pub extern "C" fn ik_status_message(status: c_int) -> *const c_char {
+    // an int like the mode of ik_tokenize, an out of range enum from C would be undefined behavior
+    usize::try_from(status)
+        .ok()
+        .and_then(|status| STATUS_MESSAGES.get(status))
+        .unwrap_or(&"unknown status\0")
+        .as_ptr() as *const c_char
+}
+
+/// Name of a lexeme type as in `LexemeType::as_str`: "CN_WORD", a static string, "unknown lexeme
+/// type" for other values.
+#[no_mangle]
+pub extern "C" fn ik_lexeme_type_name(lexeme_type: c_int) -> *const c_char {
+    usize::try_from(lexeme_type)
+        .ok()
+        .and_then(|lexeme_type| LEXEME_TYPE_NAMES.get(lexeme_type))
+        .unwrap_or(&"unknown lexeme type\0")
+        .as_ptr() as *const c_char
+}
+
+/// Create a segmenter. `config_path` may be NULL for the bundled ik.yml, dictionaries are
+/// process wide so every segmenter must use the same config.
+///
+/// # Safety
+/// `config_path` is NULL or a NUL terminated string, `out` is a valid pointer.
+#[no_mangle]
+pub unsafe extern "C" fn ik_segmenter_new(
+    config_path: *const c_char,
+    out: *mut *mut IkSegmenter,
+) -> IkStatus {
+    guard(|| {
+        if out.is_null() {
+            return IkStatus::NullPointer;
+        }
+        if !config_path.is_null() {
+            let config_path = match CStr::from_ptr(config_path).to_str() {
+                Ok(config_path) => Path::new(config_path),
+                Err(_) => return IkStatus::InvalidUtf8,
+            };
+            if global_config_path() != Some(config_path) && !set_global_config(config_path) {
+                return IkStatus::Config;
+            }
+        }
+        let segmenter = Box::new(IkSegmenter {
+            inner: IKSegmenter::new(),
+        });
+        *out = Box::into_raw(segmenter);
+        IkStatus::Ok
+    })
+}
+
+/// Destroy a segmenter, NULL is ignored.
+///
+/// # Safety
+/// `segmenter` comes from `ik_segmenter_new` and is not used afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn ik_segmenter_free(segmenter: *mut IkSegmenter) {
+    if !segmenter.is_null() {
+        drop(Box::from_raw(segmenter));
+    }
+}
+
+/// Tokenize `text_len` bytes of UTF-8 `text` in `mode`, one of `IkMode`, into the caller
+/// owned `tokens` buffer of `capacity` tokens, `out_len` receives the number of tokens.
+/// When the buffer is too small `IK_STATUS_BUFFER_TOO_SMALL` is returned with the needed
+/// number in `out_len`.
+///
+/// # Safety
+/// `text` points to `text_len` bytes, `tokens` to `capacity` tokens, `out_len` is valid.
+#[no_mangle]
+pub unsafe extern "C" fn ik_tokenize(
+    segmenter: *const IkSegmenter,
+    text: *const c_char,
+    text_len: usize,
+    mode: i32,
+    tokens: *mut IkToken,
+    capacity: usize,
+    out_len: *mut usize,
+) -> IkStatus {
+    guard(|| {
+        if out_len.is_null() || (tokens.is_null() && capacity > 0) {
+            return IkStatus::NullPointer;
+        }
+        let (input, lexemes) = match segment(segmenter, text, text_len, mode) {
+            Ok(result) => result,
+            Err(status) => return status,
+        };
+        *out_len = lexemes.len();
+        if lexemes.len() > capacity {
+            return IkStatus::BufferTooSmall;
+        }
+        let offsets = byte_offsets(&input);
+        for (i, lexeme) in lexemes.iter().enumerate() {
+            *tokens.add(i) = to_token(lexeme, &offsets, ptr::null());
+        }
+        IkStatus::Ok
+    })
+}
+
+/// Like `ik_tokenize`, but into tokens allocated by the library, with token texts.
+///
+/// # Safety
+/// `text` points to `text_len` bytes, `out` is valid, free the result with `ik_tokens_free`.
+#[no_mangle]
+pub unsafe extern "C" fn ik_tokenize_alloc(
+    segmenter: *const IkSegmenter,
+    text: *const c_char,
+    text_len: usize,
+    mode: i32,
+    out: *mut *mut IkTokens,
+) -> IkStatus {
+    guard(|| {
+        if out.is_null() {
+            return IkStatus::NullPointer;
+        }
+        let (input, lexemes) = match segment(segmenter, text, text_len, mode) {
+            Ok(result) => result,
+            Err(status) => return status,
+        };
+        let offsets = byte_offsets(&input);
+        let tokens: Box<[IkToken]> = lexemes
+            .iter()
+            .map(|lexeme| {
+                // lexeme texts never contain NUL, they are sliced from a valid C string
+                let text = CString::new(lexeme.lexeme_text()).unwrap_or_default();
+                to_token(lexeme, &offsets, text.into_raw())
+            })
+            .collect();
+        let len = tokens.len();
+        let tokens = Box::into_raw(tokens) as *mut IkToken;
+        *out = Box::into_raw(Box::new(IkTokens { tokens, len }));
+        IkStatus::Ok
+    })
+}
+
+/// Free tokens from `ik_tokenize_alloc`, NULL is ignored.
+///
+/// # Safety
+/// `tokens` comes from `ik_tokenize_alloc` and is not used afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn ik_tokens_free(tokens: *mut IkTokens) {
+    if tokens.is_null() {
+        return;
+    }
+    let tokens = Box::from_raw(tokens);
+    let tokens = Box::from_raw(ptr::slice_from_raw_parts_mut(tokens.tokens, tokens.len));
+    for token in tokens.iter() {
+        if !token.text.is_null() {
+            drop(CString::from_raw(token.text as *mut c_char));
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn test_lexeme_type_names() {
+        for (i, name) in LEXEME_TYPE_NAMES.iter().enumerate() {
+            let name = name.strip_suffix('\0').unwrap();
+            let lexeme_type = LexemeType::from_str(name).unwrap();
+            assert_eq!(i, IkLexemeType::from(&lexeme_type) as usize);
+            assert_eq!(lexeme_type.as_str(), name);
+        }
+        assert!(STATUS_MESSAGES
+            .iter()
+            .all(|message| message.ends_with('\0')));
+    }
+}
@@ -0,0 +1,65 @@
+#![cfg(unix)]
+
+use std::path::Path;
+use std::process::Command;
+use std::{env, fs};
+
+// compile tests/harness.c against the header generated by build.rs and the cdylib, then run it
+#[test]
+fn test_c_harness() {
+    let crate_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    // libik.so of this build sits next to the test binary in target/debug/deps,
+    // the copy in target/debug is only refreshed by `cargo build`
+    let exe = env::current_exe().unwrap();
+    let lib_dir = exe.parent().unwrap();
+    let harness = Path::new(env!("CARGO_TARGET_TMPDIR")).join("ik_harness");
+    let cc = env::var("CC").unwrap_or_else(|_| "cc".to_string());
+    let status = Command::new(cc)
+        .arg("-std=c99")
+        .arg("-Wall")
+        .arg("-o")
+        .arg(&harness)
+        .arg(crate_dir.join("tests/harness.c"))
+        .arg("-I")
+        .arg(env!("OUT_DIR"))
+        .arg("-L")
+        .arg(lib_dir)
+        .arg(format!("-Wl,-rpath,{}", lib_dir.display()))
+        .arg("-lik")
+        .status()
+        .expect("run cc error");
+    assert!(status.success());
+
+    // cargo test puts target/debug on LD_LIBRARY_PATH, which wins over the rpath
+    let output = Command::new(&harness)
+        .env_remove("LD_LIBRARY_PATH")
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(
+        output.status.success(),
+        "{}{}",
+        stdout,
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let lines: Vec<&str> = stdout.lines().collect();
+    assert!(lines[0].starts_with("needed "));
+    assert!(lines.contains(&"0 21 CN_WORD 1 5 中华人民共和国"));
+    assert!(lines.contains(&"22 27 LETTER 1 2 ik-rs"));
+    assert!(lines.contains(&"search 中华人民共和国"));
+    assert!(lines.contains(&"status 99: unknown status"));
+    assert!(lines.contains(&"lexeme type -1: unknown lexeme type"));
+    assert!(lines.contains(&"lexeme type 22: unknown lexeme type"));
+    assert_eq!(Some(&"done"), lines.last());
+}
+
+#[test]
+fn test_header_up_to_date() {
+    let committed = fs::read_to_string(Path::new(env!("CARGO_MANIFEST_DIR")).join("include/ik.h"));
+    let generated = fs::read_to_string(Path::new(env!("OUT_DIR")).join("ik.h")).unwrap();
+    assert_eq!(
+        Some(&generated),
+        committed.as_ref().ok(),
+        "include/ik.h is stale, run `IK_CAPI_UPDATE_HEADER=1 cargo build -p ik-capi`"
+    );
+}
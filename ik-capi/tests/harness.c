@@ -0,0 +1,60 @@
+/* exercises the C API, prints one token per line: start end type increment length text */
+#include <stdio.h>
+#include <string.h>
+
+#include "ik.h"
+
+static int check(IkStatus status, IkStatus expected, const char *what) {
+    if (status != expected) {
+        fprintf(stderr, "%s: %s\n", what, ik_status_message(status));
+        return 1;
+    }
+    return 0;
+}
+
+int main(void) {
+    const char *text = "中华人民共和国 ik-rs";
+    IkSegmenter *segmenter = NULL;
+    IkToken tokens[16];
+    size_t len = 0;
+    IkTokens *alloc = NULL;
+
+    if (check(ik_segmenter_new(NULL, &segmenter), IK_STATUS_OK, "new")) return 1;
+
+    /* too small buffer reports the needed size */
+    if (check(ik_tokenize(segmenter, text, strlen(text), IK_MODE_INDEX, tokens, 1, &len),
+              IK_STATUS_BUFFER_TOO_SMALL, "small buffer")) return 1;
+    printf("needed %zu\n", len);
+
+    if (check(ik_tokenize(segmenter, text, strlen(text), IK_MODE_INDEX, tokens, 16, &len),
+              IK_STATUS_OK, "tokenize")) return 1;
+    for (size_t i = 0; i < len; i++) {
+        printf("%zu %zu %s %u %u %.*s\n", tokens[i].start_offset, tokens[i].end_offset,
+               ik_lexeme_type_name(tokens[i].lexeme_type), tokens[i].position_increment,
+               tokens[i].position_length, (int)(tokens[i].end_offset - tokens[i].start_offset),
+               text + tokens[i].start_offset);
+    }
+
+    if (check(ik_tokenize_alloc(segmenter, text, strlen(text), IK_MODE_SEARCH, &alloc),
+              IK_STATUS_OK, "tokenize alloc")) return 1;
+    for (size_t i = 0; i < alloc->len; i++) {
+        printf("search %s\n", alloc->tokens[i].text);
+    }
+    ik_tokens_free(alloc);
+
+    if (check(ik_tokenize(segmenter, "\xff", 1, IK_MODE_SEARCH, tokens, 16, &len),
+              IK_STATUS_INVALID_UTF8, "invalid utf8")) return 1;
+    if (check(ik_tokenize(segmenter, text, strlen(text), 7, tokens, 16, &len),
+              IK_STATUS_INVALID_MODE, "invalid mode")) return 1;
+    if (check(ik_segmenter_new("/not/exist/ik.yml", &segmenter), IK_STATUS_CONFIG, "config"))
+        return 1;
+
+    /* out of range values from C */
+    printf("status 99: %s\n", ik_status_message(99));
+    printf("lexeme type -1: %s\n", ik_lexeme_type_name(-1));
+    printf("lexeme type 22: %s\n", ik_lexeme_type_name(22));
+
+    ik_segmenter_free(segmenter);
+    printf("done\n");
+    return 0;
+}
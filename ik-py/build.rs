@@ -0,0 +1,9 @@
+fn main() {
+    // `cargo test` runs an embedded interpreter, let the test binary find libpython. maturin turns
+    // on pyo3/extension-module which is not visible here, the rpath is unused by the module then
+    if std::env::var_os("CARGO_CFG_UNIX").is_some() {
+        if let Some(lib_dir) = pyo3_build_config::get().lib_dir.as_ref() {
+            println!("cargo:rustc-link-arg=-Wl,-rpath,{}", lib_dir);
+        }
+    }
+}
@@ -0,0 +1,206 @@
+//! Python bindings of ik-rs, built with maturin into the `ik_rs` module.
+//!
+//! ```python
+//! import ik_rs
+//! ik = ik_rs.IKSegmenter()
+//! ik.tokenize("中华人民共和国", ik_rs.TokenMode.INDEX)
+//! # [('中华人民共和国', 0, 7, 'CN_WORD'), ('中华人民', 0, 4, 'CN_WORD'), ...]
+//! ```
+//! Offsets are char offsets, the same as Python str indices.
+
+use std::path::Path;
+
+use ik_rs::core::ik_segmenter::{IKSegmenter, TokenMode};
+use ik_rs::dict::dictionary::{set_global_config, GLOBAL_DICT};
+use pyo3::exceptions::{PyRuntimeError, PyValueError};
+use pyo3::prelude::*;
+
+/// (token, start_offset, end_offset, type)
+type Token = (String, usize, usize, String);
+
+#[pyclass(name = "TokenMode", module = "ik_rs", eq, eq_int, frozen)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PyTokenMode {
+    /// max word segmentation, ik_max_word
+    #[pyo3(name = "INDEX")]
+    Index,
+    /// smart segmentation, ik_smart
+    #[pyo3(name = "SEARCH")]
+    Search,
+}
+
+impl From<PyTokenMode> for TokenMode {
+    fn from(mode: PyTokenMode) -> Self {
+        match mode {
+            PyTokenMode::Index => TokenMode::INDEX,
+            PyTokenMode::Search => TokenMode::SEARCH,
+        }
+    }
+}
+
+#[pyclass(name = "IKSegmenter", module = "ik_rs", frozen)]
+pub struct PyIKSegmenter {
+    inner: IKSegmenter,
+}
+
+#[pymethods]
+impl PyIKSegmenter {
+    /// options are the ones of `IKSegmenterBuilder`, with the same defaults
+    #[new]
+    #[pyo3(signature = (
+        *,
+        cjk_bigram = true,
+        group_cjk_runs = false,
+        entity_sub_tokens = true,
+        emit_symbols = false,
+        split_on_case_change = false,
+        traditional_to_simplified = false,
+    ))]
+    fn new(
+        cjk_bigram: bool,
+        group_cjk_runs: bool,
+        entity_sub_tokens: bool,
+        emit_symbols: bool,
+        split_on_case_change: bool,
+        traditional_to_simplified: bool,
+    ) -> Self {
+        let inner = IKSegmenter::builder()
+            .cjk_bigram(cjk_bigram)
+            .group_cjk_runs(group_cjk_runs)
+            .entity_sub_tokens(entity_sub_tokens)
+            .emit_symbols(emit_symbols)
+            .split_on_case_change(split_on_case_change)
+            .traditional_to_simplified(traditional_to_simplified)
+            .build();
+        PyIKSegmenter { inner }
+    }
+
+    /// segment and remove the default stop words, the GIL is released meanwhile
+    #[pyo3(signature = (text, mode = PyTokenMode::Search))]
+    fn tokenize(&self, py: Python<'_>, text: &str, mode: PyTokenMode) -> Vec<Token> {
+        py.allow_threads(|| {
+            self.inner
+                .tokenize(text, mode.into())
+                .into_iter()
+                .map(|lexeme| {
+                    (
+                        lexeme.lexeme_text().to_string(),
+                        lexeme.begin_pos(),
+                        lexeme.end_pos(),
+                        lexeme.lexeme_type().as_str().to_string(),
+                    )
+                })
+                .collect()
+        })
+    }
+}
+
+fn update_main_dict(words: Vec<String>, add: bool) -> PyResult<()> {
+    let lock_guard = {
+        cfg_if::cfg_if! {
+            if #[cfg(feature="use-parking-lot")] {Some(GLOBAL_DICT.write())}
+            else /*if #[cfg(feature="use-std-sync")]*/ {GLOBAL_DICT.write().ok()}
+        }
+    };
+    let mut dict = lock_guard.ok_or_else(|| PyRuntimeError::new_err("dictionary is poisoned"))?;
+    let words: Vec<&str> = words.iter().map(|word| word.as_str()).collect();
+    if add {
+        dict.add_words(words);
+    } else {
+        dict.disable_words(words);
+    }
+    Ok(())
+}
+
+/// add words to the main dictionary shared by all segmenters
+#[pyfunction]
+fn add_words(words: Vec<String>) -> PyResult<()> {
+    update_main_dict(words, true)
+}
+
+/// remove words from the main dictionary shared by all segmenters
+#[pyfunction]
+fn remove_words(words: Vec<String>) -> PyResult<()> {
+    update_main_dict(words, false)
+}
+
+/// load dictionaries from another ik.yml, must be called before the first segmenter
+#[pyfunction]
+fn set_config(config_path: &str) -> PyResult<()> {
+    if set_global_config(Path::new(config_path)) {
+        Ok(())
+    } else {
+        Err(PyValueError::new_err(format!(
+            "can not load config {}, or dictionaries are loaded already",
+            config_path
+        )))
+    }
+}
+
+// the module is imported as ik_rs, the name of the ik-rs crate is taken here
+#[pymodule]
+#[pyo3(name = "ik_rs")]
+fn ik_py(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyTokenMode>()?;
+    m.add_class::<PyIKSegmenter>()?;
+    m.add_function(wrap_pyfunction!(add_words, m)?)?;
+    m.add_function(wrap_pyfunction!(remove_words, m)?)?;
+    m.add_function(wrap_pyfunction!(set_config, m)?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use std::ffi::CString;
+
+    use pyo3::types::PyDict;
+
+    use super::*;
+
+    fn run(code: &str) {
+        pyo3::prepare_freethreaded_python();
+        Python::with_gil(|py| {
+            let globals = PyDict::new(py);
+            globals
+                .set_item("ik_rs", pyo3::wrap_pymodule!(ik_py)(py))
+                .unwrap();
+            let code = CString::new(code).unwrap();
+            if let Err(e) = py.run(&code, Some(&globals), None) {
+                e.print(py);
+                panic!("python error");
+            }
+        });
+    }
+
+    #[test]
+    fn test_tokenize() {
+        run(r#"
+ik = ik_rs.IKSegmenter()
+tokens = ik.tokenize("中华人民共和国", ik_rs.TokenMode.INDEX)
+assert tokens[0] == ("中华人民共和国", 0, 7, "CN_WORD"), tokens
+assert ("人民", 2, 4, "CN_WORD") in tokens, tokens
+assert ik.tokenize("中华人民共和国") == [("中华人民共和国", 0, 7, "CN_WORD")]
+text = "张三说的确实在理"
+assert all(text[start:end] == token for token, start, end, _ in ik.tokenize(text))
+ik = ik_rs.IKSegmenter(traditional_to_simplified=True)
+assert ik.tokenize("資料庫")[0][0] == "资料库"
+"#);
+    }
+
+    #[test]
+    fn test_dictionary() {
+        run(r#"
+ik = ik_rs.IKSegmenter()
+assert [t[0] for t in ik.tokenize("蓝鲸鲸鲨", ik_rs.TokenMode.SEARCH)] != ["蓝鲸鲸鲨"]
+ik_rs.add_words(["蓝鲸鲸鲨"])
+assert [t[0] for t in ik.tokenize("蓝鲸鲸鲨", ik_rs.TokenMode.SEARCH)] == ["蓝鲸鲸鲨"]
+ik_rs.remove_words(["蓝鲸鲸鲨"])
+assert [t[0] for t in ik.tokenize("蓝鲸鲸鲨", ik_rs.TokenMode.SEARCH)] != ["蓝鲸鲸鲨"]
+try:
+    ik_rs.set_config("not_exist.yml")
+    assert False
+except ValueError:
+    pass
+"#);
+    }
+}
@@ -0,0 +1,236 @@
+//! SQLite FTS5 tokenizer driving `IKSegmenter`.
+//!
+//! ```
+//! use rusqlite::Connection;
+//! let conn = Connection::open_in_memory().unwrap();
+//! ik_sqlite::register_tokenizer(&conn).unwrap();
+//! conn.execute_batch(
+//!     "CREATE VIRTUAL TABLE docs USING fts5(body, tokenize = 'ik');
+//!      INSERT INTO docs VALUES ('中华人民共和国成立了');",
+//! )
+//! .unwrap();
+//! let count: i64 = conn
+//!     .query_row("SELECT count(*) FROM docs WHERE docs MATCH '人民'", [], |row| row.get(0))
+//!     .unwrap();
+//! assert_eq!(1, count);
+//! ```
+//! `tokenize = 'ik'` segments in INDEX mode, overlapping lexemes are reported with
+//! `FTS5_TOKEN_COLOCATED` so that all of them are searchable at one position.
+//! `tokenize = 'ik search'` segments in SEARCH mode. Offsets are byte offsets.
+
+use std::ffi::{c_char, c_int, c_void, CStr, CString};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::ptr;
+use std::slice;
+
+use ik_rs::core::ik_segmenter::{IKSegmenter, TokenMode};
+use ik_rs::core::lexeme::Lexeme;
+use rusqlite::ffi;
+use rusqlite::Connection;
+
+/// name for the `tokenize` option of fts5 tables
+pub const TOKENIZER_NAME: &str = "ik";
+
+type TokenCallback = unsafe extern "C" fn(
+    ctx: *mut c_void,
+    flags: c_int,
+    token: *const c_char,
+    token_len: c_int,
+    start: c_int,
+    end: c_int,
+) -> c_int;
+
+struct IkTokenizer {
+    segmenter: IKSegmenter,
+    mode: TokenMode,
+}
+
+impl IkTokenizer {
+    // arguments after the tokenizer name: `tokenize = 'ik search'`
+    fn new(args: &[&str]) -> Option<Self> {
+        let mode = match args {
+            [] | ["index"] => TokenMode::INDEX,
+            ["search"] => TokenMode::SEARCH,
+            _ => return None,
+        };
+        Some(IkTokenizer {
+            segmenter: IKSegmenter::new(),
+            mode,
+        })
+    }
+
+    // lexemes with byte offsets and fts5 token flags
+    fn tokenize(&self, text: &str) -> Vec<(Lexeme, usize, usize, c_int)> {
+        let mut byte_offsets: Vec<usize> = text.char_indices().map(|(i, _)| i).collect();
+        byte_offsets.push(text.len());
+        self.segmenter
+            .tokenize(text, self.mode.clone())
+            .into_iter()
+            .map(|lexeme| {
+                let start = byte_offsets[lexeme.begin_pos()];
+                let end = byte_offsets[lexeme.end_pos()];
+                let flags = if lexeme.position_increment() == 0 {
+                    ffi::FTS5_TOKEN_COLOCATED
+                } else {
+                    0
+                };
+                (lexeme, start, end, flags)
+            })
+            .collect()
+    }
+}
+
+unsafe extern "C" fn x_create(
+    _user_data: *mut c_void,
+    args: *mut *const c_char,
+    arg_count: c_int,
+    out: *mut *mut ffi::Fts5Tokenizer,
+) -> c_int {
+    let result = catch_unwind(|| {
+        let args = if arg_count > 0 {
+            slice::from_raw_parts(args, arg_count as usize)
+        } else {
+            &[]
+        };
+        let args: Option<Vec<&str>> = args
+            .iter()
+            .map(|arg| CStr::from_ptr(*arg).to_str().ok())
+            .collect();
+        args.and_then(|args| IkTokenizer::new(&args))
+    });
+    match result {
+        Ok(Some(tokenizer)) => {
+            *out = Box::into_raw(Box::new(tokenizer)) as *mut ffi::Fts5Tokenizer;
+            ffi::SQLITE_OK
+        }
+        _ => ffi::SQLITE_ERROR,
+    }
+}
+
+unsafe extern "C" fn x_delete(tokenizer: *mut ffi::Fts5Tokenizer) {
+    if !tokenizer.is_null() {
+        drop(Box::from_raw(tokenizer as *mut IkTokenizer));
+    }
+}
+
+unsafe extern "C" fn x_tokenize(
+    tokenizer: *mut ffi::Fts5Tokenizer,
+    ctx: *mut c_void,
+    _flags: c_int,
+    text: *const c_char,
+    text_len: c_int,
+    token_callback: Option<TokenCallback>,
+) -> c_int {
+    let (tokenizer, token_callback) = match (tokenizer.is_null(), token_callback) {
+        (false, Some(token_callback)) => (&*(tokenizer as *const IkTokenizer), token_callback),
+        _ => return ffi::SQLITE_MISUSE,
+    };
+    let bytes = if text.is_null() || text_len <= 0 {
+        &[]
+    } else {
+        slice::from_raw_parts(text as *const u8, text_len as usize)
+    };
+    let text = match std::str::from_utf8(bytes) {
+        Ok(text) => text,
+        Err(_) => return ffi::SQLITE_ERROR,
+    };
+    let tokens = match catch_unwind(AssertUnwindSafe(|| tokenizer.tokenize(text))) {
+        Ok(tokens) => tokens,
+        Err(_) => return ffi::SQLITE_ERROR,
+    };
+    for (lexeme, start, end, flags) in tokens.iter() {
+        let token = lexeme.lexeme_text();
+        let rc = token_callback(
+            ctx,
+            *flags,
+            token.as_ptr() as *const c_char,
+            token.len() as c_int,
+            *start as c_int,
+            *end as c_int,
+        );
+        if rc != ffi::SQLITE_OK {
+            return rc;
+        }
+    }
+    ffi::SQLITE_OK
+}
+
+// the fts5_api of a connection, see https://sqlite.org/fts5.html#extending_fts5
+unsafe fn fts5_api(db: *mut ffi::sqlite3) -> Result<*mut ffi::fts5_api, c_int> {
+    let mut stmt = ptr::null_mut();
+    // NUL terminated str, c"" literals are not supported by the pinned toolchain
+    let rc = ffi::sqlite3_prepare_v2(
+        db,
+        "SELECT fts5(?1)\0".as_ptr() as *const c_char,
+        -1,
+        &mut stmt,
+        ptr::null_mut(),
+    );
+    if rc != ffi::SQLITE_OK {
+        return Err(rc);
+    }
+    let mut api: *mut ffi::fts5_api = ptr::null_mut();
+    ffi::sqlite3_bind_pointer(
+        stmt,
+        1,
+        &mut api as *mut *mut ffi::fts5_api as *mut c_void,
+        "fts5_api_ptr\0".as_ptr() as *const c_char,
+        None,
+    );
+    ffi::sqlite3_step(stmt);
+    let rc = ffi::sqlite3_finalize(stmt);
+    if rc != ffi::SQLITE_OK {
+        return Err(rc);
+    }
+    if api.is_null() || (*api).iVersion < 2 {
+        return Err(ffi::SQLITE_ERROR);
+    }
+    Ok(api)
+}
+
+/// register the `ik` tokenizer on a connection, tables created afterwards may use
+/// `tokenize = 'ik'` or `tokenize = 'ik search'`
+pub fn register_tokenizer(conn: &Connection) -> rusqlite::Result<()> {
+    let error = |rc: c_int, message: &str| {
+        rusqlite::Error::SqliteFailure(ffi::Error::new(rc), Some(message.to_string()))
+    };
+    unsafe {
+        let api = fts5_api(conn.handle()).map_err(|rc| error(rc, "fts5 is not available"))?;
+        let create_tokenizer = (*api)
+            .xCreateTokenizer
+            .ok_or_else(|| error(ffi::SQLITE_ERROR, "fts5 is not available"))?;
+        // fts5 copies the callbacks
+        let mut tokenizer = ffi::fts5_tokenizer {
+            xCreate: Some(x_create),
+            xDelete: Some(x_delete),
+            xTokenize: Some(x_tokenize),
+        };
+        let name = CString::new(TOKENIZER_NAME).unwrap();
+        let rc = create_tokenizer(api, name.as_ptr(), ptr::null_mut(), &mut tokenizer, None);
+        if rc != ffi::SQLITE_OK {
+            return Err(error(rc, "register ik tokenizer error"));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_tokenize() {
+        let tokenizer = IkTokenizer::new(&[]).unwrap();
+        let text = "我爱中华人民共和国";
+        let tokens: Vec<(&str, usize, usize, c_int)> = tokenizer
+            .tokenize(text)
+            .into_iter()
+            .map(|(_, start, end, flags)| (&text[start..end], start, end, flags))
+            .collect();
+        assert_eq!(("我", 0, 3, 0), tokens[0]);
+        assert!(tokens.contains(&("中华人民共和国", 6, 27, 0)));
+        assert!(tokens.contains(&("中华", 6, 12, ffi::FTS5_TOKEN_COLOCATED)));
+        assert!(IkTokenizer::new(&["search"]).is_some());
+        assert!(IkTokenizer::new(&["unknown"]).is_none());
+    }
+}
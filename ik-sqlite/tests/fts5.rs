@@ -0,0 +1,68 @@
+use ik_sqlite::register_tokenizer;
+use rusqlite::Connection;
+
+fn connection(tokenize: &str) -> Connection {
+    let conn = Connection::open_in_memory().unwrap();
+    register_tokenizer(&conn).unwrap();
+    conn.execute_batch(&format!(
+        "CREATE VIRTUAL TABLE docs USING fts5(title, body, tokenize = '{}');",
+        tokenize
+    ))
+    .unwrap();
+    let mut insert = conn.prepare("INSERT INTO docs VALUES (?1, ?2)").unwrap();
+    insert
+        .execute(["中华人民共和国", "我爱中华人民共和国的大好河山"])
+        .unwrap();
+    insert
+        .execute(["Rust", "张三说的确实在理，ik-rs is a Chinese segmenter"])
+        .unwrap();
+    drop(insert);
+    conn
+}
+
+fn search(conn: &Connection, query: &str) -> Vec<String> {
+    let mut stmt = conn
+        .prepare("SELECT title FROM docs WHERE docs MATCH ?1 ORDER BY rowid")
+        .unwrap();
+    stmt.query_map([query], |row| row.get(0))
+        .unwrap()
+        .map(|title| title.unwrap())
+        .collect()
+}
+
+#[test]
+fn test_match() {
+    let conn = connection("ik");
+    assert_eq!(vec!["中华人民共和国"], search(&conn, "人民"));
+    // a sub word of an INDEX mode overlap, colocated with 中华人民共和国
+    assert_eq!(vec!["中华人民共和国"], search(&conn, "共和国"));
+    assert_eq!(
+        vec!["中华人民共和国"],
+        search(&conn, "\"中华人民共和国 的 大好河山\"")
+    );
+    assert_eq!(vec!["Rust"], search(&conn, "张三 AND segmenter"));
+    assert_eq!(vec!["Rust"], search(&conn, "body:确实"));
+    assert!(search(&conn, "北京").is_empty());
+}
+
+#[test]
+fn test_highlight() {
+    let conn = connection("ik search");
+    let highlighted: String = conn
+        .query_row(
+            "SELECT highlight(docs, 1, '[', ']') FROM docs WHERE docs MATCH '大好河山'",
+            [],
+            |row| row.get(0),
+        )
+        .unwrap();
+    assert_eq!("我爱中华人民共和国的[大好河山]", highlighted);
+}
+
+#[test]
+fn test_unknown_mode() {
+    let conn = Connection::open_in_memory().unwrap();
+    register_tokenizer(&conn).unwrap();
+    assert!(conn
+        .execute_batch("CREATE VIRTUAL TABLE docs USING fts5(body, tokenize = 'ik fast');")
+        .is_err());
+}
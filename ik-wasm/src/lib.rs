@@ -0,0 +1,113 @@
+//! WebAssembly bindings of ik-rs, built with `wasm-pack build --target web`.
+//!
+//! There is no file system in the browser, dictionaries are passed in as bytes:
+//! ```js
+//! import init, { loadDictionaries, Segmenter, TokenMode } from "./pkg/ik_wasm.js";
+//! await init();
+//! const bytes = async (url) => new Uint8Array(await (await fetch(url)).arrayBuffer());
+//! loadDictionaries(await bytes("main2012.dic"), await bytes("quantifier.dic"), new Uint8Array());
+//! const tokens = new Segmenter().tokenize("中华人民共和国", TokenMode.Index);
+//! ```
+//! Offsets count UTF-16 code units, the same as JavaScript string indices.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use ik_rs::core::ik_segmenter::IKSegmenter;
+use ik_rs::dict::dictionary::{set_global_dictionary, Dictionary};
+use wasm_bindgen::prelude::*;
+
+static DICT_LOADED: AtomicBool = AtomicBool::new(false);
+
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenMode {
+    /// max word segmentation, ik_max_word
+    Index = 0,
+    /// smart segmentation, ik_smart
+    Search = 1,
+}
+
+impl From<TokenMode> for ik_rs::core::ik_segmenter::TokenMode {
+    fn from(mode: TokenMode) -> Self {
+        match mode {
+            TokenMode::Index => ik_rs::core::ik_segmenter::TokenMode::INDEX,
+            TokenMode::Search => ik_rs::core::ik_segmenter::TokenMode::SEARCH,
+        }
+    }
+}
+
+#[wasm_bindgen(getter_with_clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Token {
+    pub text: String,
+    #[wasm_bindgen(js_name = startOffset)]
+    pub start_offset: usize,
+    #[wasm_bindgen(js_name = endOffset)]
+    pub end_offset: usize,
+    #[wasm_bindgen(js_name = type)]
+    pub token_type: String,
+    pub position: usize,
+}
+
+/// load the dictionaries once before creating segmenters, every buffer holds the lines
+/// of a .dic file, main2012.dic and ext.dic may be concatenated into `main_dict`
+#[wasm_bindgen(js_name = loadDictionaries)]
+pub fn load_dictionaries(
+    main_dict: &[u8],
+    quantifier_dict: &[u8],
+    stop_word_dict: &[u8],
+) -> Result<(), String> {
+    if DICT_LOADED.load(Ordering::Acquire) {
+        return Err("dictionaries are loaded already".to_string());
+    }
+    let dict = Dictionary::from_bytes(&[main_dict], quantifier_dict, &[stop_word_dict], &[])?;
+    if !set_global_dictionary(dict) {
+        return Err("dictionaries are loaded already".to_string());
+    }
+    DICT_LOADED.store(true, Ordering::Release);
+    Ok(())
+}
+
+#[wasm_bindgen]
+pub struct Segmenter {
+    inner: IKSegmenter,
+}
+
+#[wasm_bindgen]
+impl Segmenter {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Result<Segmenter, String> {
+        if !DICT_LOADED.load(Ordering::Acquire) {
+            return Err("call loadDictionaries first".to_string());
+        }
+        Ok(Segmenter {
+            inner: IKSegmenter::new(),
+        })
+    }
+
+    /// segment and remove the default stop words
+    pub fn tokenize(&self, text: &str, mode: TokenMode) -> Vec<Token> {
+        let mut utf16_offsets = Vec::with_capacity(text.len() + 1);
+        let mut utf16_offset = 0;
+        for c in text.chars() {
+            utf16_offsets.push(utf16_offset);
+            utf16_offset += c.len_utf16();
+        }
+        utf16_offsets.push(utf16_offset);
+        let mut position = 0;
+        self.inner
+            .tokenize(text, mode.into())
+            .iter()
+            .map(|lexeme| {
+                position += lexeme.position_increment();
+                Token {
+                    text: lexeme.lexeme_text().to_string(),
+                    start_offset: utf16_offsets[lexeme.begin_pos()],
+                    end_offset: utf16_offsets[lexeme.end_pos()],
+                    token_type: lexeme.lexeme_type().as_str().to_string(),
+                    position: position - 1,
+                }
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,53 @@
+// run in node with `cargo test --target wasm32-unknown-unknown`, natively as plain tests
+use std::sync::Once;
+
+use ik_wasm::{load_dictionaries, Segmenter, Token, TokenMode};
+use wasm_bindgen_test::wasm_bindgen_test;
+
+const MAIN_DICT: &[u8] = include_bytes!("../../dict/main2012.dic");
+const QUANTIFIER_DICT: &[u8] = include_bytes!("../../dict/quantifier.dic");
+
+static INIT: Once = Once::new();
+
+fn segmenter() -> Segmenter {
+    INIT.call_once(|| {
+        let main_dict = [MAIN_DICT, "\n蓝鲸鲸鲨\n".as_bytes()].concat();
+        load_dictionaries(&main_dict, QUANTIFIER_DICT, &[]).unwrap();
+    });
+    Segmenter::new().unwrap()
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test_tokenize() {
+    let tokens = segmenter().tokenize("中华人民共和国", TokenMode::Index);
+    assert_eq!(
+        Token {
+            text: "中华人民共和国".to_string(),
+            start_offset: 0,
+            end_offset: 7,
+            token_type: "CN_WORD".to_string(),
+            position: 0,
+        },
+        tokens[0]
+    );
+    // a word only in the main_dict buffer
+    let texts: Vec<String> = segmenter()
+        .tokenize("蓝鲸鲸鲨", TokenMode::Search)
+        .into_iter()
+        .map(|token| token.text)
+        .collect();
+    assert_eq!(vec!["蓝鲸鲸鲨"], texts);
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test_utf16_offsets() {
+    let tokens = segmenter().tokenize("😀人民", TokenMode::Search);
+    let token = tokens.iter().find(|token| token.text == "人民").unwrap();
+    assert_eq!((2, 4), (token.start_offset, token.end_offset));
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test_load_twice() {
+    segmenter();
+    assert!(load_dictionaries(MAIN_DICT, QUANTIFIER_DICT, &[]).is_err());
+}
@@ -0,0 +1,317 @@
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::path::PathBuf;
+use std::process;
+
+use ik_rs::core::ik_segmenter::{IKSegmenter, TokenMode};
+use ik_rs::core::lexeme::Lexeme;
+use ik_rs::dict::dictionary::set_global_config;
+use serde_json::json;
+
+const USAGE: &str = "Usage: ik [OPTIONS] [TEXT]...
+
+Segment TEXT, or every line of the input files, or every line of stdin.
+
+Options:
+  -m, --mode <index|search>       segment mode [default: search]
+  -f, --file <PATH>               read lines from a file, may be repeated
+  -c, --config <PATH>             ik.yml to load dictionaries from
+  -o, --format <plain|tsv|json>   output format [default: plain]
+  -h, --help                      print this help
+
+Output:
+  plain  tokens of a line separated by spaces
+  tsv    line, start_offset, end_offset, type, position, token per row
+  json   one JSON object per token (JSON Lines)
+offsets are char offsets in the line";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Format {
+    Plain,
+    Tsv,
+    Json,
+}
+
+struct Args {
+    mode: TokenMode,
+    format: Format,
+    config: Option<PathBuf>,
+    files: Vec<PathBuf>,
+    texts: Vec<String>,
+}
+
+fn take_value(
+    name: &str,
+    inline_value: Option<String>,
+    args: &mut impl Iterator<Item = String>,
+) -> Result<String, String> {
+    inline_value
+        .or_else(|| args.next())
+        .ok_or(format!("missing value of {}", name))
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Args, String> {
+    let mut parsed = Args {
+        mode: TokenMode::SEARCH,
+        format: Format::Plain,
+        config: None,
+        files: Vec::new(),
+        texts: Vec::new(),
+    };
+    while let Some(arg) = args.next() {
+        // --mode=index
+        let (name, inline_value) = match arg.split_once('=') {
+            Some((name, value)) if name.starts_with("--") => (name, Some(value.to_string())),
+            _ => (arg.as_str(), None),
+        };
+        match name {
+            "-h" | "--help" => {
+                println!("{}", USAGE);
+                process::exit(0);
+            }
+            "-m" | "--mode" => {
+                parsed.mode = match take_value(name, inline_value, &mut args)?.as_str() {
+                    "index" => TokenMode::INDEX,
+                    "search" => TokenMode::SEARCH,
+                    mode => return Err(format!("unknown mode: {}", mode)),
+                }
+            }
+            "-o" | "--format" => {
+                parsed.format = match take_value(name, inline_value, &mut args)?.as_str() {
+                    "plain" => Format::Plain,
+                    "tsv" => Format::Tsv,
+                    "json" | "jsonl" => Format::Json,
+                    format => return Err(format!("unknown format: {}", format)),
+                }
+            }
+            "-c" | "--config" => {
+                parsed.config = Some(PathBuf::from(take_value(name, inline_value, &mut args)?))
+            }
+            "-f" | "--file" => {
+                parsed
+                    .files
+                    .push(PathBuf::from(take_value(name, inline_value, &mut args)?))
+            }
+            "--" => parsed.texts.extend(args.by_ref()),
+            _ if arg.starts_with('-') && arg.len() > 1 => {
+                return Err(format!("unknown option: {}", arg))
+            }
+            _ => parsed.texts.push(arg),
+        }
+    }
+    Ok(parsed)
+}
+
+fn write_tokens(
+    out: &mut impl Write,
+    format: Format,
+    line_no: usize,
+    tokens: &[Lexeme],
+) -> io::Result<()> {
+    match format {
+        Format::Plain => {
+            let texts: Vec<&str> = tokens.iter().map(|t| t.lexeme_text()).collect();
+            writeln!(out, "{}", texts.join(" "))
+        }
+        Format::Tsv => {
+            let mut position = 0;
+            for token in tokens.iter() {
+                position += token.position_increment();
+                writeln!(
+                    out,
+                    "{}\t{}\t{}\t{}\t{}\t{}",
+                    line_no,
+                    token.begin_pos(),
+                    token.end_pos(),
+                    token.lexeme_type().as_str(),
+                    position - 1,
+                    token.lexeme_text()
+                )?;
+            }
+            Ok(())
+        }
+        Format::Json => {
+            let mut position = 0;
+            for token in tokens.iter() {
+                position += token.position_increment();
+                let value = json!({
+                    "line": line_no,
+                    "token": token.lexeme_text(),
+                    "start_offset": token.begin_pos(),
+                    "end_offset": token.end_pos(),
+                    "type": token.lexeme_type().as_str(),
+                    "position": position - 1,
+                    "position_length": token.position_length(),
+                });
+                writeln!(out, "{}", value)?;
+            }
+            Ok(())
+        }
+    }
+}
+
+fn segment_lines(
+    ik: &IKSegmenter,
+    args: &Args,
+    reader: impl BufRead,
+    line_no: &mut usize,
+    out: &mut impl Write,
+) -> io::Result<()> {
+    for line in reader.lines() {
+        let line = line?;
+        *line_no += 1;
+        let tokens = ik.tokenize(&line, args.mode.clone());
+        write_tokens(out, args.format, *line_no, &tokens)?;
+    }
+    Ok(())
+}
+
+fn run(args: Args) -> Result<(), String> {
+    if let Some(config) = args.config.as_ref() {
+        if !set_global_config(config) {
+            return Err(format!("can not load config: {}", config.display()));
+        }
+    }
+    let ik = IKSegmenter::new();
+    let stdout = io::stdout();
+    let mut out = BufWriter::new(stdout.lock());
+    let mut line_no = 0;
+    if !args.texts.is_empty() {
+        let text = args.texts.join(" ");
+        segment_lines(&ik, &args, text.as_bytes(), &mut line_no, &mut out)
+            .map_err(|e| e.to_string())?;
+    }
+    for path in args.files.iter() {
+        let file = File::open(path).map_err(|e| format!("open {} error: {}", path.display(), e))?;
+        segment_lines(&ik, &args, BufReader::new(file), &mut line_no, &mut out)
+            .map_err(|e| format!("read {} error: {}", path.display(), e))?;
+    }
+    if args.texts.is_empty() && args.files.is_empty() {
+        segment_lines(&ik, &args, io::stdin().lock(), &mut line_no, &mut out)
+            .map_err(|e| e.to_string())?;
+    }
+    out.flush().map_err(|e| e.to_string())
+}
+
+fn main() {
+    let result = parse_args(std::env::args().skip(1)).and_then(run);
+    if let Err(e) = result {
+        eprintln!("ik: {}\n\n{}", e, USAGE);
+        process::exit(2);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Args, String> {
+        parse_args(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn test_parse_args() {
+        let args = parse(&[]).unwrap();
+        assert!(matches!(args.mode, TokenMode::SEARCH));
+        assert_eq!(Format::Plain, args.format);
+        assert!(args.config.is_none() && args.files.is_empty() && args.texts.is_empty());
+
+        let args = parse(&[
+            "-m",
+            "index",
+            "--format=json",
+            "-c",
+            "ik.yml",
+            "-f",
+            "a.txt",
+            "--file",
+            "b.txt",
+            "中华",
+            "人民",
+        ])
+        .unwrap();
+        assert!(matches!(args.mode, TokenMode::INDEX));
+        assert_eq!(Format::Json, args.format);
+        assert_eq!(Some(PathBuf::from("ik.yml")), args.config);
+        assert_eq!(
+            vec![PathBuf::from("a.txt"), PathBuf::from("b.txt")],
+            args.files
+        );
+        assert_eq!(vec!["中华", "人民"], args.texts);
+
+        let args = parse(&["--mode=search", "-o", "tsv", "--", "-m", "--x"]).unwrap();
+        assert!(matches!(args.mode, TokenMode::SEARCH));
+        assert_eq!(Format::Tsv, args.format);
+        assert_eq!(vec!["-m", "--x"], args.texts);
+        assert_eq!(vec!["-"], parse(&["-"]).unwrap().texts);
+    }
+
+    #[test]
+    fn test_parse_args_error() {
+        assert_eq!(Some("unknown option: -x".to_string()), parse(&["-x"]).err());
+        assert_eq!(
+            Some("unknown option: --verbose".to_string()),
+            parse(&["--verbose"]).err()
+        );
+        assert_eq!(
+            Some("missing value of --mode".to_string()),
+            parse(&["--mode"]).err()
+        );
+        assert_eq!(
+            Some("missing value of -f".to_string()),
+            parse(&["-f"]).err()
+        );
+        assert_eq!(
+            Some("unknown mode: smart".to_string()),
+            parse(&["-m", "smart"]).err()
+        );
+        assert_eq!(
+            Some("unknown format: xml".to_string()),
+            parse(&["--format=xml"]).err()
+        );
+    }
+
+    fn output(format: Format, text: &str) -> String {
+        let ik = IKSegmenter::new();
+        let tokens = ik.tokenize(text, TokenMode::INDEX);
+        let mut out = Vec::new();
+        write_tokens(&mut out, format, 3, &tokens).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn test_write_tokens() {
+        assert_eq!(
+            "中华人民共和国 中华人民 中华 华人 人民共和国 人民 共和国 共和 国\n",
+            output(Format::Plain, "中华人民共和国")
+        );
+        let tsv = output(Format::Tsv, "中华人民共和国");
+        let rows: Vec<&str> = tsv.lines().collect();
+        assert_eq!(9, rows.len());
+        assert_eq!("3\t0\t7\tCN_WORD\t0\t中华人民共和国", rows[0]);
+        assert_eq!("3\t1\t3\tCN_WORD\t1\t华人", rows[3]);
+
+        let json = output(Format::Json, "中华人民共和国");
+        let values: Vec<serde_json::Value> = json
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(9, values.len());
+        assert_eq!(
+            json!({
+                "line": 3,
+                "token": "中华人民共和国",
+                "start_offset": 0,
+                "end_offset": 7,
+                "type": "CN_WORD",
+                "position": 0,
+                "position_length": 5,
+            }),
+            values[0]
+        );
+        // an empty line is still a line in plain output, no rows otherwise
+        assert_eq!("\n", output(Format::Plain, ""));
+        assert_eq!("", output(Format::Tsv, ""));
+        assert_eq!("", output(Format::Json, ""));
+    }
+}
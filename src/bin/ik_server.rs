@@ -0,0 +1,263 @@
+use std::path::PathBuf;
+use std::process;
+
+use ik_rs::core::ik_segmenter::{IKSegmenter, TokenMode};
+use ik_rs::dict::dictionary::set_global_config;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use tiny_http::{Header, Method, Response, Server};
+
+const USAGE: &str = "Usage: ik-server [OPTIONS]
+
+Serve Elasticsearch compatible POST /_analyze with ik_smart and ik_max_word analyzers.
+
+Options:
+  -a, --addr <HOST:PORT>   listen address [default: 127.0.0.1:9200]
+  -c, --config <PATH>      ik.yml to load dictionaries from
+  -h, --help               print this help";
+
+// same gaps as Elasticsearch between the values of an array `text`
+const POSITION_INCREMENT_GAP: usize = 100;
+const OFFSET_GAP: usize = 1;
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum AnalyzeText {
+    One(String),
+    Many(Vec<String>),
+}
+
+#[derive(Debug, Deserialize)]
+struct AnalyzeRequest {
+    analyzer: Option<String>,
+    tokenizer: Option<String>,
+    text: AnalyzeText,
+}
+
+#[derive(Debug, Serialize)]
+struct AnalyzeToken {
+    token: String,
+    start_offset: usize,
+    end_offset: usize,
+    #[serde(rename = "type")]
+    token_type: String,
+    position: usize,
+}
+
+#[derive(Debug, Serialize)]
+struct AnalyzeResponse {
+    tokens: Vec<AnalyzeToken>,
+}
+
+fn token_mode(analyzer: &str) -> Option<TokenMode> {
+    match analyzer {
+        "ik_smart" => Some(TokenMode::SEARCH),
+        "ik_max_word" => Some(TokenMode::INDEX),
+        _ => None,
+    }
+}
+
+fn error_body(status: u16, error_type: &str, reason: &str) -> String {
+    let cause = json!({"type": error_type, "reason": reason});
+    json!({
+        "error": {"root_cause": [cause], "type": error_type, "reason": reason},
+        "status": status,
+    })
+    .to_string()
+}
+
+/// handle the body of an _analyze request, returns http status and json body
+fn analyze(ik: &IKSegmenter, body: &str) -> (u16, String) {
+    let request: AnalyzeRequest = match serde_json::from_str(body) {
+        Ok(request) => request,
+        Err(e) => return (400, error_body(400, "parse_exception", &e.to_string())),
+    };
+    // Elasticsearch falls back to the standard analyzer, which ik-server does not serve
+    let analyzer = request
+        .analyzer
+        .or(request.tokenizer)
+        .unwrap_or_else(|| "standard".to_string());
+    let mode = match token_mode(&analyzer) {
+        Some(mode) => mode,
+        None => {
+            let reason = format!("failed to find global analyzer [{}]", analyzer);
+            return (400, error_body(400, "illegal_argument_exception", &reason));
+        }
+    };
+    let texts = match request.text {
+        AnalyzeText::One(text) => vec![text],
+        AnalyzeText::Many(texts) => texts,
+    };
+    let mut tokens = Vec::new();
+    // sum of position increments, the position of a token is one less
+    let mut position = 0;
+    let mut offset_base = 0;
+    for (i, text) in texts.iter().enumerate() {
+        if i > 0 {
+            position += POSITION_INCREMENT_GAP;
+            offset_base += OFFSET_GAP;
+        }
+        // Elasticsearch offsets count UTF-16 code units
+        let mut utf16_offsets = Vec::with_capacity(text.len() + 1);
+        let mut utf16_offset = 0;
+        for c in text.chars() {
+            utf16_offsets.push(utf16_offset);
+            utf16_offset += c.len_utf16();
+        }
+        utf16_offsets.push(utf16_offset);
+        for lexeme in ik.tokenize(text, mode.clone()).iter() {
+            position += lexeme.position_increment();
+            tokens.push(AnalyzeToken {
+                token: lexeme.lexeme_text().to_string(),
+                start_offset: offset_base + utf16_offsets[lexeme.begin_pos()],
+                end_offset: offset_base + utf16_offsets[lexeme.end_pos()],
+                token_type: lexeme.lexeme_type().as_str().to_string(),
+                position: position - 1,
+            });
+        }
+        offset_base += utf16_offset;
+    }
+    let body = serde_json::to_string(&AnalyzeResponse { tokens }).unwrap();
+    (200, body)
+}
+
+// POST|GET /_analyze | /{index}/_analyze
+fn is_analyze_path(url: &str) -> bool {
+    let path = url.split('?').next().unwrap_or("");
+    let segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+    matches!(segments.as_slice(), ["_analyze"] | [_, "_analyze"])
+}
+
+fn serve(server: Server, ik: IKSegmenter) {
+    let content_type =
+        Header::from_bytes("Content-Type", "application/json; charset=UTF-8").unwrap();
+    for mut request in server.incoming_requests() {
+        let (status, body) = if !is_analyze_path(request.url()) {
+            let reason = format!("no handler found for uri [{}]", request.url());
+            (404, error_body(404, "illegal_argument_exception", &reason))
+        } else if !matches!(request.method(), Method::Post | Method::Get) {
+            let reason = format!("method [{}] is not allowed", request.method());
+            (405, error_body(405, "illegal_argument_exception", &reason))
+        } else {
+            let mut body = String::new();
+            match request.as_reader().read_to_string(&mut body) {
+                Ok(_) => analyze(&ik, &body),
+                Err(e) => (400, error_body(400, "parse_exception", &e.to_string())),
+            }
+        };
+        let response = Response::from_string(body)
+            .with_status_code(status)
+            .with_header(content_type.clone());
+        if let Err(e) = request.respond(response) {
+            eprintln!("ik-server: respond error: {}", e);
+        }
+    }
+}
+
+fn take_value(name: &str, args: &mut impl Iterator<Item = String>) -> Result<String, String> {
+    args.next().ok_or(format!("missing value of {}", name))
+}
+
+fn exit_usage(message: &str) -> ! {
+    eprintln!("ik-server: {}\n\n{}", message, USAGE);
+    process::exit(2);
+}
+
+fn main() {
+    let mut addr = "127.0.0.1:9200".to_string();
+    let mut config: Option<PathBuf> = None;
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => {
+                println!("{}", USAGE);
+                return;
+            }
+            "-a" | "--addr" => {
+                addr = take_value(&arg, &mut args).unwrap_or_else(|e| exit_usage(&e))
+            }
+            "-c" | "--config" => {
+                let value = take_value(&arg, &mut args).unwrap_or_else(|e| exit_usage(&e));
+                config = Some(PathBuf::from(value))
+            }
+            _ => exit_usage(&format!("unknown option: {}", arg)),
+        }
+    }
+    if let Some(config) = config {
+        if !set_global_config(&config) {
+            eprintln!("ik-server: can not load config: {}", config.display());
+            process::exit(2);
+        }
+    }
+    let ik = IKSegmenter::new();
+    let server = match Server::http(&addr) {
+        Ok(server) => server,
+        Err(e) => {
+            eprintln!("ik-server: can not listen on {}: {}", addr, e);
+            process::exit(1);
+        }
+    };
+    println!("ik-server listening on http://{}", addr);
+    serve(server, ik);
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_analyze() {
+        let ik = IKSegmenter::new();
+        let (status, body) = analyze(&ik, r#"{"analyzer":"ik_smart","text":"中华人民共和国"}"#);
+        assert_eq!(200, status);
+        assert_eq!(
+            r#"{"tokens":[{"token":"中华人民共和国","start_offset":0,"end_offset":7,"type":"CN_WORD","position":0}]}"#,
+            body
+        );
+        let (_, body) = analyze(
+            &ik,
+            r#"{"analyzer":"ik_max_word","text":["中华","𠀀人民"]}"#,
+        );
+        let response: serde_json::Value = serde_json::from_str(&body).unwrap();
+        let tokens: Vec<(&str, u64, u64)> = response["tokens"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|t| {
+                (
+                    t["token"].as_str().unwrap(),
+                    t["start_offset"].as_u64().unwrap(),
+                    t["position"].as_u64().unwrap(),
+                )
+            })
+            .collect();
+        assert_eq!(vec![("中华", 0, 0), ("人民", 5, 101)], tokens);
+        let (status, body) = analyze(&ik, r#"{"analyzer":"standard","text":"x"}"#);
+        assert_eq!(400, status);
+        assert!(body.contains("failed to find global analyzer [standard]"));
+        let (status, body) = analyze(&ik, r#"{"text":"x"}"#);
+        assert_eq!(400, status);
+        assert!(body.contains("failed to find global analyzer [standard]"));
+        assert_eq!(400, analyze(&ik, "{").0);
+    }
+
+    #[test]
+    fn test_take_value() {
+        let mut args = vec!["0.0.0.0:9200".to_string()].into_iter();
+        assert_eq!(
+            Ok("0.0.0.0:9200".to_string()),
+            take_value("--addr", &mut args)
+        );
+        assert_eq!(
+            Err("missing value of -a".to_string()),
+            take_value("-a", &mut args)
+        );
+    }
+
+    #[test]
+    fn test_is_analyze_path() {
+        assert!(is_analyze_path("/_analyze"));
+        assert!(is_analyze_path("/my_index/_analyze?pretty"));
+        assert!(!is_analyze_path("/_search"));
+    }
+}
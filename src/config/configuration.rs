@@ -4,4 +4,7 @@ pub trait Configuration {
     fn get_quantifier_dictionary(&self) -> String;
     fn get_ext_dictionaries(&self) -> Vec<String>;
     fn get_ext_stop_word_dictionaries(&self) -> Vec<String>;
+    fn get_synonym_dictionaries(&self) -> Vec<String>;
+    fn get_letter_connectors(&self) -> Vec<char>;
+    fn get_num_connectors(&self) -> Vec<char>;
 }
@@ -7,6 +7,7 @@ use serde::{Deserialize, Serialize};
 use {serde, serde_yaml};
 
 use crate::config::configuration::Configuration;
+use crate::core::letter_segmentor::{LETTER_CONNECTOR, NUM_CONNECTOR};
 
 // 分词器配置文件路径
 const IK_CONFIG_NAME: &str = "ik.yml";
@@ -18,6 +19,40 @@ pub struct DefaultConfig {
     stop_word_dict: String,
     ext_dicts: Vec<String>,
     ext_stop_word_dicts: Vec<String>,
+    #[serde(default)]
+    synonym_dicts: Vec<String>,
+    #[serde(default = "default_letter_connectors")]
+    letter_connectors: String,
+    #[serde(default = "default_num_connectors")]
+    num_connectors: String,
+    // dictionary paths are relative to it
+    #[serde(skip)]
+    root_path: String,
+}
+
+fn default_letter_connectors() -> String {
+    LETTER_CONNECTOR.iter().collect()
+}
+
+fn default_num_connectors() -> String {
+    NUM_CONNECTOR.iter().collect()
+}
+
+// no dictionary files and the default connectors, for dictionaries loaded from memory
+impl Default for DefaultConfig {
+    fn default() -> Self {
+        DefaultConfig {
+            main_dict: String::new(),
+            quantifier_dict: String::new(),
+            stop_word_dict: String::new(),
+            ext_dicts: Vec::new(),
+            ext_stop_word_dicts: Vec::new(),
+            synonym_dicts: Vec::new(),
+            letter_connectors: default_letter_connectors(),
+            num_connectors: default_num_connectors(),
+            root_path: String::new(),
+        }
+    }
 }
 
 impl DefaultConfig {
@@ -30,32 +65,46 @@ impl DefaultConfig {
         reader
             .read_to_string(&mut yaml_str)
             .expect("read ik.yml error");
-        let config: DefaultConfig = serde_yaml::from_str(yaml_str.as_str()).expect("json error");
+        let mut config: DefaultConfig =
+            serde_yaml::from_str(yaml_str.as_str()).expect("json error");
+        config.root_path = format!("{}/", root_path);
         config
     }
-}
 
-fn root_path() -> String {
-    let mut root_path = env!("CARGO_MANIFEST_DIR").to_string();
-    root_path.push('/');
-    root_path
+    /// load a config file outside the crate, dictionary paths in it are relative to its directory
+    pub fn from_file(conf_file_path: &Path) -> Result<DefaultConfig, String> {
+        let yaml_str = std::fs::read_to_string(conf_file_path)
+            .map_err(|e| format!("read {} error: {}", conf_file_path.display(), e))?;
+        let mut config: DefaultConfig = serde_yaml::from_str(yaml_str.as_str())
+            .map_err(|e| format!("parse {} error: {}", conf_file_path.display(), e))?;
+        let root_path = conf_file_path.parent().unwrap_or(Path::new(""));
+        config.root_path = match root_path.to_str() {
+            Some("") | None => String::new(),
+            Some(root_path) => format!("{}/", root_path),
+        };
+        Ok(config)
+    }
+
+    fn root_path(&self) -> String {
+        self.root_path.clone()
+    }
 }
 
 impl Configuration for DefaultConfig {
     fn get_main_dictionary(&self) -> String {
-        let mut root_path = root_path();
+        let mut root_path = self.root_path();
         root_path.push_str(self.main_dict.as_str());
         root_path
     }
 
     fn get_quantifier_dictionary(&self) -> String {
-        let mut root_path = root_path();
+        let mut root_path = self.root_path();
         root_path.push_str(self.quantifier_dict.as_str());
         root_path
     }
 
     fn get_ext_dictionaries(&self) -> Vec<String> {
-        let root_path = root_path();
+        let root_path = self.root_path();
         let dicts = self
             .ext_dicts
             .iter()
@@ -66,7 +115,7 @@ impl Configuration for DefaultConfig {
 
     fn get_ext_stop_word_dictionaries(&self) -> Vec<String> {
         let mut dicts = Vec::new();
-        let root_path = root_path();
+        let root_path = self.root_path();
         dicts.push(root_path.clone() + self.stop_word_dict.as_str());
         let ext_stopwords = self
             .ext_stop_word_dicts
@@ -76,6 +125,22 @@ impl Configuration for DefaultConfig {
         dicts.extend(ext_stopwords);
         dicts
     }
+
+    fn get_synonym_dictionaries(&self) -> Vec<String> {
+        let root_path = self.root_path();
+        self.synonym_dicts
+            .iter()
+            .map(|dict| root_path.clone() + dict)
+            .collect()
+    }
+
+    fn get_letter_connectors(&self) -> Vec<char> {
+        self.letter_connectors.chars().collect()
+    }
+
+    fn get_num_connectors(&self) -> Vec<char> {
+        self.num_connectors.chars().collect()
+    }
 }
 
 #[cfg(test)]
@@ -92,5 +157,19 @@ mod test {
         log::info!("{}", config.get_quantifier_dictionary());
         log::info!("{:?}", config.get_ext_dictionaries());
         log::info!("{:?}", config.get_ext_stop_word_dictionaries());
+        log::info!("{:?}", config.get_synonym_dictionaries());
+        assert_eq!(LETTER_CONNECTOR.to_vec(), config.get_letter_connectors());
+        assert_eq!(NUM_CONNECTOR.to_vec(), config.get_num_connectors());
+    }
+
+    #[test]
+    pub fn test_config_from_file() {
+        let conf_file_path = Path::new(env!("CARGO_MANIFEST_DIR")).join(IK_CONFIG_NAME);
+        let config = DefaultConfig::from_file(&conf_file_path).unwrap();
+        assert_eq!(
+            DefaultConfig::new().get_main_dictionary(),
+            config.get_main_dictionary()
+        );
+        assert!(DefaultConfig::from_file(Path::new("not_exist.yml")).is_err());
     }
 }
@@ -0,0 +1,55 @@
+use crate::core::ik_segmenter::{IKSegmenter, TokenMode};
+use crate::core::lexeme::Lexeme;
+use crate::filter::stop_word_filter::StopWordFilter;
+use crate::filter::token_filter::TokenFilter;
+
+/// analysis chain: IKSegmenter output passes through the filters in order
+/// ```
+/// use ik_rs::core::analyzer::Analyzer;
+/// use ik_rs::core::ik_segmenter::{IKSegmenter, TokenMode};
+/// use ik_rs::filter::length_filter::LengthFilter;
+/// use ik_rs::filter::stop_word_filter::StopWordFilter;
+/// let analyzer = Analyzer::new(IKSegmenter::new())
+///     .filter(StopWordFilter::new())
+///     .filter(LengthFilter::new(2, 10));
+/// let tokens = analyzer.analyze("我是中国人", TokenMode::SEARCH);
+/// assert_eq!("中国人", tokens[0].lexeme_text());
+/// ```
+pub struct Analyzer {
+    segmenter: IKSegmenter,
+    filters: Vec<Box<dyn TokenFilter>>,
+}
+
+impl Default for Analyzer {
+    /// same output as `IKSegmenter::tokenize`
+    fn default() -> Self {
+        Analyzer::new(IKSegmenter::new()).filter(StopWordFilter::new())
+    }
+}
+
+impl Analyzer {
+    pub fn new(segmenter: IKSegmenter) -> Self {
+        Analyzer {
+            segmenter,
+            filters: Vec::new(),
+        }
+    }
+
+    /// append a filter to the end of the chain
+    pub fn filter<F: TokenFilter + 'static>(mut self, filter: F) -> Self {
+        self.filters.push(Box::new(filter));
+        self
+    }
+
+    pub fn filters(&self) -> Vec<&str> {
+        self.filters.iter().map(|filter| filter.name()).collect()
+    }
+
+    pub fn analyze(&self, text: &str, mode: TokenMode) -> Vec<Lexeme> {
+        let mut lexemes = self.segmenter.segment(text, mode);
+        for filter in self.filters.iter() {
+            lexemes = filter.filter(lexemes);
+        }
+        lexemes
+    }
+}
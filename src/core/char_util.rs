@@ -7,6 +7,7 @@ pub enum CharType {
     ENGLISH,
     CHINESE,
     OtherCjk,
+    SYMBOL,
 }
 
 impl From<char> for CharType {
@@ -31,6 +32,17 @@ impl From<char> for CharType {
                     || ub == unicode_blocks::KATAKANA_PHONETIC_EXTENSIONS
                 {
                     return CharType::OtherCjk;
+                } else if is_emoji(input)
+                    || ub == unicode_blocks::CURRENCY_SYMBOLS
+                    || ub == unicode_blocks::LETTERLIKE_SYMBOLS
+                    || ub == unicode_blocks::ARROWS
+                    || ub == unicode_blocks::MATHEMATICAL_OPERATORS
+                    || ub == unicode_blocks::MISCELLANEOUS_TECHNICAL
+                    || ub == unicode_blocks::GEOMETRIC_SHAPES
+                    || LATIN1_CURRENCY.contains(&input)
+                    || ASCII_SYMBOLS.contains(&input)
+                {
+                    return CharType::SYMBOL;
                 }
             }
         }
@@ -38,6 +50,58 @@ impl From<char> for CharType {
     }
 }
 
+// ¢ £ ¤ ¥
+const LATIN1_CURRENCY: [char; 4] = ['\u{A2}', '\u{A3}', '\u{A4}', '\u{A5}'];
+
+// ascii currency & math signs
+const ASCII_SYMBOLS: [char; 7] = ['$', '+', '<', '=', '>', '|', '~'];
+
+/// emoji & pictographs, a subset of `CharType::SYMBOL`
+pub fn is_emoji(input: char) -> bool {
+    if let Some(ub) = unicode_blocks::find_unicode_block(input) {
+        return ub == unicode_blocks::MISCELLANEOUS_SYMBOLS_AND_PICTOGRAPHS
+            || ub == unicode_blocks::EMOTICONS
+            || ub == unicode_blocks::TRANSPORT_AND_MAP_SYMBOLS
+            || ub == unicode_blocks::SUPPLEMENTAL_SYMBOLS_AND_PICTOGRAPHS
+            || ub == unicode_blocks::SYMBOLS_AND_PICTOGRAPHS_EXTENDED_A
+            || ub == unicode_blocks::MISCELLANEOUS_SYMBOLS
+            || ub == unicode_blocks::DINGBATS
+            || ub == unicode_blocks::ENCLOSED_ALPHANUMERIC_SUPPLEMENT; // regional indicators
+    }
+    false
+}
+
+/// script of an `OtherCjk` char, used to build bigrams and runs
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CjkScript {
+    Hiragana,
+    Katakana,
+    Hangul,
+}
+
+pub fn cjk_script(input: char) -> Option<CjkScript> {
+    match input {
+        '\u{FF66}'..='\u{FF9F}' => return Some(CjkScript::Katakana), // halfwidth katakana
+        '\u{FFA0}'..='\u{FFDC}' => return Some(CjkScript::Hangul),   // halfwidth hangul
+        _ => {}
+    }
+    if let Some(ub) = unicode_blocks::find_unicode_block(input) {
+        if ub == unicode_blocks::HIRAGANA {
+            return Some(CjkScript::Hiragana);
+        } else if ub == unicode_blocks::KATAKANA
+            || ub == unicode_blocks::KATAKANA_PHONETIC_EXTENSIONS
+        {
+            return Some(CjkScript::Katakana);
+        } else if ub == unicode_blocks::HANGUL_SYLLABLES
+            || ub == unicode_blocks::HANGUL_JAMO
+            || ub == unicode_blocks::HANGUL_COMPATIBILITY_JAMO
+        {
+            return Some(CjkScript::Hangul);
+        }
+    }
+    None
+}
+
 // full char -> half char && lowercase
 pub fn regularize(input: char) -> char {
     let mut input_code = u32::from(input);
@@ -87,7 +151,6 @@ pub fn utf8_slice(s: &str, begin: usize, end: usize) -> &str {
         .unwrap_or("")
 }
 
-#[allow(dead_code)]
 pub fn utf8_from(s: &str, begin: usize) -> &str {
     utf8_slice(s, begin, utf8_len(s))
 }
@@ -100,3 +163,22 @@ pub fn utf8_till(s: &str, end: usize) -> &str {
 pub fn utf8_len(s: &str) -> usize {
     s.chars().count()
 }
+
+/// chars of the input a segmenter is analyzing, collected once instead of per cursor
+#[derive(Debug, Default)]
+pub struct InputChars {
+    // address & byte length of the input the chars are collected from
+    key: (usize, usize),
+    chars: Vec<char>,
+}
+
+impl InputChars {
+    pub fn get(&mut self, input: &str) -> &[char] {
+        let key = (input.as_ptr() as usize, input.len());
+        if self.key != key {
+            self.key = key;
+            self.chars = input.chars().collect();
+        }
+        &self.chars
+    }
+}
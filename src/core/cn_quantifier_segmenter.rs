@@ -1,7 +1,10 @@
 use std::collections::HashSet;
 
-use crate::core::char_util::{utf8_len, CharType};
+use crate::core::char_util::{utf8_len, CharType, InputChars};
 use crate::core::lexeme::{Lexeme, LexemeType};
+use crate::core::number_util::{
+    cn_digit, is_cn_number_char, DECIMAL_POINT, FRACTION_INFIX, PERCENT_PREFIX,
+};
 use crate::core::ordered_linked_list::OrderedLinkedList;
 use crate::core::segmentor::Segmenter;
 use crate::dict::dictionary::GLOBAL_DICT;
@@ -13,6 +16,7 @@ pub struct CnQuantifierSegmenter {
     start: Option<usize>,
     end: Option<usize>,
     chn_number_chars: HashSet<char>,
+    input_chars: InputChars,
 }
 
 impl Segmenter for CnQuantifierSegmenter {
@@ -23,6 +27,7 @@ impl Segmenter for CnQuantifierSegmenter {
         curr_char_type: &CharType,
         origin_lexemes: &mut OrderedLinkedList<Lexeme>,
     ) {
+        self.process_numeric_form(input, cursor, origin_lexemes);
         self.process_cnumber(input, cursor, curr_char_type, origin_lexemes);
         self.process_count(input, cursor, curr_char_type, origin_lexemes);
     }
@@ -41,6 +46,7 @@ impl Default for CnQuantifierSegmenter {
                 '叁', '肆', '伍', '陆', '柒', '捌', '玖', '拾', '百', '千', '万', '亿', '拾', '佰',
                 '仟', '萬', '億', '兆', '卅', '廿',
             ]),
+            input_chars: InputChars::default(),
         }
     }
 }
@@ -87,6 +93,82 @@ impl CnQuantifierSegmenter {
         });
     }
 
+    /// decimal | percentage | fraction
+    /// 三点五 | 百分之三十 | 3.5% | 三分之一
+    fn process_numeric_form(
+        &mut self,
+        input: &str,
+        cursor: usize,
+        origin_lexemes: &mut OrderedLinkedList<Lexeme>,
+    ) {
+        let chars = self.input_chars.get(input);
+        let curr_char = chars[cursor];
+        if !is_cn_number_char(curr_char) && !curr_char.is_ascii_digit() {
+            return;
+        }
+        if cursor > 0 {
+            let prev_char = chars[cursor - 1];
+            if is_cn_number_char(prev_char) || prev_char.is_ascii_digit() || prev_char == '.' {
+                return; // only scan from the start of a number
+            }
+        }
+        let numeric_form = if chars[cursor..].starts_with(&PERCENT_PREFIX) {
+            let end = Self::scan_number(chars, cursor + PERCENT_PREFIX.len(), true);
+            (end > cursor + PERCENT_PREFIX.len()).then_some((end, LexemeType::PERCENT))
+        } else {
+            let end = Self::scan_number(chars, cursor, false);
+            if chars[end..].starts_with(&FRACTION_INFIX) {
+                let numerator_end = Self::scan_number(chars, end + FRACTION_INFIX.len(), true);
+                (numerator_end > end + FRACTION_INFIX.len())
+                    .then_some((numerator_end, LexemeType::FRACTION))
+            } else if chars.get(end) == Some(&'%') && chars[cursor].is_ascii_digit() {
+                Some((end + 1, LexemeType::PERCENT))
+            } else if chars.get(end) == Some(&DECIMAL_POINT) && !chars[cursor].is_ascii_digit() {
+                let fraction_end = Self::scan_decimal_digits(chars, end + 1);
+                (fraction_end > end + 1).then_some((fraction_end, LexemeType::DECIMAL))
+            } else {
+                None
+            }
+        };
+        if let Some((end, lexeme_type)) = numeric_form {
+            origin_lexemes.insert(Lexeme::new(cursor..end, lexeme_type));
+        }
+    }
+
+    // end of an arabic number or chinese numeral, optionally with a decimal part
+    fn scan_number(chars: &[char], begin: usize, with_decimal: bool) -> usize {
+        let mut end = begin;
+        if chars.get(begin).is_some_and(|c| c.is_ascii_digit()) {
+            while end < chars.len()
+                && (chars[end].is_ascii_digit()
+                    || (chars[end] == '.'
+                        && chars.get(end + 1).is_some_and(|c| c.is_ascii_digit())))
+            {
+                end += 1;
+            }
+        } else {
+            while end < chars.len() && is_cn_number_char(chars[end]) {
+                end += 1;
+            }
+            if with_decimal && end > begin && chars.get(end) == Some(&DECIMAL_POINT) {
+                let fraction_end = Self::scan_decimal_digits(chars, end + 1);
+                if fraction_end > end + 1 {
+                    end = fraction_end;
+                }
+            }
+        }
+        end
+    }
+
+    // 点 is followed by digits only: 三点一四
+    fn scan_decimal_digits(chars: &[char], begin: usize) -> usize {
+        let mut end = begin;
+        while end < chars.len() && cn_digit(chars[end]).is_some() {
+            end += 1;
+        }
+        end
+    }
+
     fn process_count(
         &mut self,
         input: &str,
@@ -0,0 +1,331 @@
+use crate::core::char_util::{CharType, InputChars};
+use crate::core::lexeme::{Lexeme, LexemeType};
+use crate::core::number_util::{cn_digit, parse_cn_number};
+use crate::core::ordered_linked_list::OrderedLinkedList;
+use crate::core::segmentor::Segmenter;
+
+const SEGMENTER_NAME: &str = "DATETIME_SEGMENTER";
+
+const DAY_PERIODS: [(&str, DayPeriod); 10] = [
+    ("凌晨", DayPeriod::Morning),
+    ("早上", DayPeriod::Morning),
+    ("上午", DayPeriod::Morning),
+    ("中午", DayPeriod::Noon),
+    ("下午", DayPeriod::Afternoon),
+    ("傍晚", DayPeriod::Afternoon),
+    ("晚上", DayPeriod::Night),
+    ("夜里", DayPeriod::Night),
+    ("早", DayPeriod::Morning),
+    ("晚", DayPeriod::Night),
+];
+
+#[derive(Debug, Clone, Copy)]
+enum DayPeriod {
+    Morning,
+    Noon,
+    Afternoon,
+    // 晚上十二点 is midnight
+    Night,
+}
+
+/// date & time expression
+/// 2024年10月18日 | 二〇二四年 | 下午三点半 | 2024-10-18 | 2024-10-18t08:30 | 12:30:05
+#[derive(Default, Debug)]
+pub struct DateTimeSegmenter {
+    input_chars: InputChars,
+}
+
+impl Segmenter for DateTimeSegmenter {
+    fn analyze(
+        &mut self,
+        input: &str,
+        cursor: usize,
+        _curr_char_type: &CharType,
+        origin_lexemes: &mut OrderedLinkedList<Lexeme>,
+    ) {
+        let chars = self.input_chars.get(input);
+        let curr_char = chars[cursor];
+        let period_start = DAY_PERIODS
+            .iter()
+            .any(|(period, _)| period.starts_with(curr_char));
+        if !period_start && !is_date_number_char(curr_char) {
+            return;
+        }
+        if cursor > 0 && is_date_number_char(chars[cursor - 1]) {
+            return; // only scan from the start of a number
+        }
+        if let Some((end, _)) = scan_datetime(chars, cursor) {
+            origin_lexemes.insert(Lexeme::new(cursor..end, LexemeType::DATETIME));
+        }
+    }
+
+    fn name(&self) -> &str {
+        SEGMENTER_NAME
+    }
+}
+
+/// normalized ISO 8601 value of a whole date/time expression:
+/// 2024年10月18日 -> 2024-10-18, 十月一日 -> --10-01, 下午三点半 -> 15:30
+pub fn parse_datetime(text: &str) -> Option<String> {
+    let chars: Vec<char> = text.chars().collect();
+    match scan_datetime(&chars, 0) {
+        Some((end, value)) if end == chars.len() => Some(value),
+        _ => None,
+    }
+}
+
+fn scan_datetime(chars: &[char], begin: usize) -> Option<(usize, String)> {
+    scan_iso_datetime(chars, begin).or_else(|| scan_cn_datetime(chars, begin))
+}
+
+fn is_date_number_char(c: char) -> bool {
+    c.is_ascii_digit() || cn_digit(c).is_some() || c == '十' || c == '廿' || c == '卅'
+}
+
+// 2024-10-18 | 2024/10/18 | 2024-10-18t08:30:00 | 08:30
+fn scan_iso_datetime(chars: &[char], begin: usize) -> Option<(usize, String)> {
+    let date = scan_arabic(chars, begin, 4, 4).and_then(|(year_end, year)| {
+        let sep = *chars.get(year_end)?;
+        if sep != '-' && sep != '/' {
+            return None;
+        }
+        let (month_end, month) = scan_arabic(chars, year_end + 1, 1, 2)?;
+        if chars.get(month_end) != Some(&sep) {
+            return None;
+        }
+        let (day_end, day) = scan_arabic(chars, month_end + 1, 1, 2)?;
+        format_date(Some(year), Some(month), Some(day)).map(|date| (day_end, date))
+    });
+    match date {
+        Some((date_end, date)) => match chars.get(date_end) {
+            Some('t') | Some(' ') => match scan_iso_time(chars, date_end + 1) {
+                Some((time_end, time)) => Some((time_end, format!("{}T{}", date, time))),
+                None => Some((date_end, date)),
+            },
+            _ => Some((date_end, date)),
+        },
+        None => scan_iso_time(chars, begin),
+    }
+    .filter(|(end, _)| !chars.get(*end).is_some_and(|c| c.is_ascii_digit()))
+}
+
+fn scan_iso_time(chars: &[char], begin: usize) -> Option<(usize, String)> {
+    let (hour_end, hour) = scan_arabic(chars, begin, 1, 2)?;
+    if chars.get(hour_end) != Some(&':') {
+        return None;
+    }
+    let (minute_end, minute) = scan_arabic(chars, hour_end + 1, 2, 2)?;
+    let (end, second) = match chars.get(minute_end) {
+        Some(':') => match scan_arabic(chars, minute_end + 1, 2, 2) {
+            Some((second_end, second)) => (second_end, Some(second)),
+            None => (minute_end, None),
+        },
+        _ => (minute_end, None),
+    };
+    format_time(hour, minute, second).map(|time| (end, time))
+}
+
+// [年][月][日|号][上午|下午..][点|时][分|半|刻][秒]
+fn scan_cn_datetime(chars: &[char], begin: usize) -> Option<(usize, String)> {
+    let mut cursor = begin;
+    let mut year = None;
+    let mut month = None;
+    let mut day = None;
+    if let Some((end, y)) = scan_year(chars, cursor) {
+        if chars.get(end) == Some(&'年') {
+            year = Some(y);
+            cursor = end + 1;
+        }
+    }
+    if let Some((end, m)) = scan_small_number(chars, cursor) {
+        if chars.get(end) == Some(&'月') && (1..=12).contains(&m) {
+            month = Some(m);
+            cursor = end + 1;
+        }
+    }
+    // a bare 三号 | 10号 is rather 三号线 | 10号楼 than a day
+    if month.is_some() {
+        if let Some((end, d)) = scan_small_number(chars, cursor) {
+            if matches!(chars.get(end), Some('日') | Some('号')) && (1..=31).contains(&d) {
+                day = Some(d);
+                cursor = end + 1;
+            }
+        }
+    }
+    let date = format_date(year, month, day);
+    if date.is_none() {
+        cursor = begin; // 十月 alone is no date, nor part of a time
+    }
+    let time = scan_cn_time(chars, cursor, date.is_some());
+    match (date, time) {
+        (Some(date), Some((end, time))) => Some((end, format!("{}T{}", date, time))),
+        (Some(date), None) => Some((cursor, date)),
+        (None, Some((end, time))) => Some((end, time)),
+        (None, None) => None,
+    }
+}
+
+fn scan_cn_time(chars: &[char], begin: usize, after_date: bool) -> Option<(usize, String)> {
+    let mut cursor = begin;
+    let mut period = None;
+    for (period_text, day_period) in DAY_PERIODS.iter() {
+        let period_chars: Vec<char> = period_text.chars().collect();
+        if chars[cursor..].starts_with(&period_chars) {
+            period = Some(*day_period);
+            cursor += period_chars.len();
+            break;
+        }
+    }
+    let (hour_end, mut hour) = scan_small_number(chars, cursor)?;
+    if !matches!(chars.get(hour_end), Some('点') | Some('时')) || hour > 24 {
+        return None;
+    }
+    cursor = hour_end + 1;
+    let mut minute = None;
+    if chars.get(cursor) == Some(&'半') {
+        minute = Some(30);
+        cursor += 1;
+    } else if chars[cursor..].starts_with(&['一', '刻']) {
+        minute = Some(15);
+        cursor += 2;
+    } else if chars[cursor..].starts_with(&['三', '刻']) {
+        minute = Some(45);
+        cursor += 2;
+    } else if let Some((end, m)) = scan_small_number(chars, cursor) {
+        if chars.get(end) == Some(&'分') && m < 60 {
+            minute = Some(m);
+            cursor = end + 1;
+        }
+    }
+    let mut second = None;
+    if minute.is_some() {
+        if let Some((end, s)) = scan_small_number(chars, cursor) {
+            if chars.get(end) == Some(&'秒') && s < 60 {
+                second = Some(s);
+                cursor = end + 1;
+            }
+        }
+    }
+    // 三点 alone is ambiguous
+    if period.is_none() && minute.is_none() && !after_date {
+        return None;
+    }
+    match period {
+        Some(DayPeriod::Afternoon) | Some(DayPeriod::Night) if hour < 12 => hour += 12,
+        Some(DayPeriod::Night) if hour == 12 => hour = 0,
+        Some(DayPeriod::Noon) if hour < 11 => hour += 12,
+        _ => {}
+    }
+    format_time(hour, minute.unwrap_or(0), second).map(|time| (cursor, time))
+}
+
+// 2024 | 二〇二四
+fn scan_year(chars: &[char], begin: usize) -> Option<(usize, u32)> {
+    if let Some(year) = scan_arabic(chars, begin, 4, 4) {
+        return Some(year);
+    }
+    let end = begin + 4;
+    if end > chars.len() || !chars[begin..end].iter().all(|c| cn_digit(*c).is_some()) {
+        return None;
+    }
+    let text: String = chars[begin..end].iter().collect();
+    parse_cn_number(&text).map(|year| (end, year as u32))
+}
+
+// 1-2 arabic digits or a chinese numeral below one hundred: 18 | 十八 | 廿三
+fn scan_small_number(chars: &[char], begin: usize) -> Option<(usize, u32)> {
+    if let Some(number) = scan_arabic(chars, begin, 1, 2) {
+        return Some(number);
+    }
+    let mut end = begin;
+    while end < chars.len() && end - begin < 3 && is_date_number_char(chars[end]) {
+        end += 1;
+    }
+    if end == begin {
+        return None;
+    }
+    let text: String = chars[begin..end].iter().collect();
+    parse_cn_number(&text).map(|number| (end, number as u32))
+}
+
+fn scan_arabic(chars: &[char], begin: usize, min: usize, max: usize) -> Option<(usize, u32)> {
+    let mut end = begin;
+    while end < chars.len() && chars[end].is_ascii_digit() {
+        end += 1;
+    }
+    if end - begin < min || end - begin > max {
+        return None;
+    }
+    let text: String = chars[begin..end].iter().collect();
+    text.parse::<u32>().ok().map(|number| (end, number))
+}
+
+fn format_date(year: Option<u32>, month: Option<u32>, day: Option<u32>) -> Option<String> {
+    if month.is_some_and(|m| !(1..=12).contains(&m)) || day.is_some_and(|d| !(1..=31).contains(&d))
+    {
+        return None;
+    }
+    match (year, month, day) {
+        (Some(y), Some(m), Some(d)) => Some(format!("{:04}-{:02}-{:02}", y, m, d)),
+        (Some(y), Some(m), None) => Some(format!("{:04}-{:02}", y, m)),
+        (Some(y), None, None) => Some(format!("{:04}", y)),
+        (None, Some(m), Some(d)) => Some(format!("--{:02}-{:02}", m, d)),
+        _ => None,
+    }
+}
+
+fn format_time(hour: u32, minute: u32, second: Option<u32>) -> Option<String> {
+    if hour > 24 || minute > 59 || second.is_some_and(|s| s > 59) {
+        return None;
+    }
+    // 24:00 is the end of a day
+    if hour == 24 && (minute > 0 || second.is_some_and(|s| s > 0)) {
+        return None;
+    }
+    match second {
+        Some(s) => Some(format!("{:02}:{:02}:{:02}", hour, minute, s)),
+        None => Some(format!("{:02}:{:02}", hour, minute)),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_datetime() {
+        assert_eq!(
+            Some("2024-10-18".to_string()),
+            parse_datetime("2024年10月18日")
+        );
+        assert_eq!(Some("2024".to_string()), parse_datetime("二〇二四年"));
+        assert_eq!(Some("--10-01".to_string()), parse_datetime("十月一号"));
+        assert_eq!(Some("15:30".to_string()), parse_datetime("下午三点半"));
+        assert_eq!(Some("08:15".to_string()), parse_datetime("早上八点一刻"));
+        assert_eq!(
+            Some("--05-04T20:00".to_string()),
+            parse_datetime("五月四日晚上八点")
+        );
+        assert_eq!(Some("2024-10-18".to_string()), parse_datetime("2024-10-18"));
+        assert_eq!(
+            Some("2024-01-02T08:30:05".to_string()),
+            parse_datetime("2024/01/02 08:30:05")
+        );
+        assert_eq!(Some("12:30".to_string()), parse_datetime("12:30"));
+        assert_eq!(None, parse_datetime("三点"));
+        assert_eq!(None, parse_datetime("三年"));
+        assert_eq!(None, parse_datetime("三号"));
+        assert_eq!(None, parse_datetime("10号"));
+        assert_eq!(None, parse_datetime("2024-13-01"));
+        assert_eq!(Some("00:00".to_string()), parse_datetime("晚上十二点"));
+        assert_eq!(Some("00:30".to_string()), parse_datetime("夜里十二点半"));
+        assert_eq!(Some("12:00".to_string()), parse_datetime("中午十二点"));
+        assert_eq!(Some("22:00".to_string()), parse_datetime("晚上十点"));
+        assert_eq!(Some("24:00".to_string()), parse_datetime("24:00"));
+        assert_eq!(None, parse_datetime("24:30"));
+        assert_eq!(None, parse_datetime("二十四点半"));
+        assert_eq!(None, parse_datetime("10月"));
+        assert_eq!(None, parse_datetime("十月下午三点"));
+        assert_eq!(Some("2024-10".to_string()), parse_datetime("2024年10月"));
+    }
+}
@@ -0,0 +1,319 @@
+use std::net::{Ipv4Addr, Ipv6Addr};
+use std::ops::Range;
+
+use crate::core::char_util::{CharType, InputChars};
+use crate::core::lexeme::{Lexeme, LexemeType};
+use crate::core::ordered_linked_list::OrderedLinkedList;
+use crate::core::segmentor::Segmenter;
+
+const SEGMENTER_NAME: &str = "ENTITY_SEGMENTER";
+
+const URL_SCHEMES: [&str; 4] = ["http://", "https://", "ftp://", "www."];
+
+const URL_CHARS: &str = "-._~:/?#[]@!$&'()*+,;=%";
+
+const URL_TRAILING_CHARS: &str = ".,;:!?)'";
+
+const EMAIL_LOCAL_CHARS: &str = "._%+-";
+
+/// url | email | ip | phone
+/// https://github.com/blueshen/ik-rs | zhiyi.shen@gmail.com | 192.168.1.1 | 2001:db8::1 | 138-1234-5678
+/// host and path parts of an url are emitted as sub lexemes when `sub_tokens` is on
+#[derive(Debug)]
+pub struct EntitySegmenter {
+    sub_tokens: bool,
+    // end of the last entity, no entity starts inside another one
+    entity_end: usize,
+    input_chars: InputChars,
+}
+
+impl Segmenter for EntitySegmenter {
+    fn analyze(
+        &mut self,
+        input: &str,
+        cursor: usize,
+        _curr_char_type: &CharType,
+        origin_lexemes: &mut OrderedLinkedList<Lexeme>,
+    ) {
+        if cursor < self.entity_end {
+            return;
+        }
+        let chars = self.input_chars.get(input);
+        let curr_char = chars[cursor];
+        if !curr_char.is_ascii_alphanumeric() && curr_char != '+' {
+            return;
+        }
+        if in_word(chars, cursor) {
+            return; // only scan from the start of a token
+        }
+        let entity = if let Some(end) = scan_url(chars, cursor) {
+            if self.sub_tokens {
+                for part in url_parts(chars, cursor..end) {
+                    origin_lexemes.insert(Lexeme::new(part, LexemeType::LETTER));
+                }
+            }
+            Some((end, LexemeType::URL))
+        } else if let Some(end) = scan_email(chars, cursor) {
+            Some((end, LexemeType::EMAIL))
+        } else if let Some(end) = scan_ip(chars, cursor) {
+            Some((end, LexemeType::IP))
+        } else {
+            scan_phone(chars, cursor).map(|end| (end, LexemeType::PHONE))
+        };
+        if let Some((end, lexeme_type)) = entity {
+            origin_lexemes.insert(Lexeme::new(cursor..end, lexeme_type));
+            self.entity_end = end;
+        }
+    }
+
+    fn name(&self) -> &str {
+        SEGMENTER_NAME
+    }
+}
+
+impl EntitySegmenter {
+    pub fn new(sub_tokens: bool) -> Self {
+        EntitySegmenter {
+            sub_tokens,
+            entity_end: 0,
+            input_chars: InputChars::default(),
+        }
+    }
+}
+
+// inside a word: abc13812345678 | a.b@gmail.com, a label before is fine: 电话:13812345678
+fn in_word(chars: &[char], cursor: usize) -> bool {
+    let prev = match cursor.checked_sub(1) {
+        Some(prev) => chars[prev],
+        None => return false,
+    };
+    prev.is_ascii_alphanumeric()
+        || (EMAIL_LOCAL_CHARS.contains(prev)
+            && cursor >= 2
+            && chars[cursor - 2].is_ascii_alphanumeric())
+}
+
+fn starts_with(chars: &[char], begin: usize, prefix: &str) -> bool {
+    let prefix: Vec<char> = prefix.chars().collect();
+    chars
+        .get(begin..)
+        .is_some_and(|rest| rest.starts_with(&prefix))
+}
+
+fn scan_url(chars: &[char], begin: usize) -> Option<usize> {
+    let scheme = URL_SCHEMES
+        .iter()
+        .find(|scheme| starts_with(chars, begin, scheme))?;
+    let mut end = begin + scheme.chars().count();
+    while end < chars.len()
+        && (chars[end].is_ascii_alphanumeric() || URL_CHARS.contains(chars[end]))
+    {
+        end += 1;
+    }
+    while end > begin && URL_TRAILING_CHARS.contains(chars[end - 1]) {
+        end -= 1;
+    }
+    let host = url_host(chars, begin..end)?;
+    if host.is_empty() {
+        return None;
+    }
+    Some(end)
+}
+
+fn url_host(chars: &[char], url: Range<usize>) -> Option<Range<usize>> {
+    let scheme = URL_SCHEMES
+        .iter()
+        .find(|scheme| starts_with(chars, url.start, scheme))?;
+    let mut host_begin = url.start;
+    if scheme.ends_with("://") {
+        host_begin += scheme.chars().count();
+    }
+    let mut host_end = host_begin;
+    while host_end < url.end && !"/?#".contains(chars[host_end]) {
+        host_end += 1;
+    }
+    // user:password@host:port
+    if let Some(at) = (host_begin..host_end).rev().find(|i| chars[*i] == '@') {
+        host_begin = at + 1;
+    }
+    if let Some(colon) = (host_begin..host_end).find(|i| chars[*i] == ':') {
+        host_end = colon;
+    }
+    Some(host_begin..host_end)
+}
+
+// host | path parts
+fn url_parts(chars: &[char], url: Range<usize>) -> Vec<Range<usize>> {
+    let mut parts = Vec::new();
+    let host = match url_host(chars, url.clone()) {
+        Some(host) => host,
+        None => return parts,
+    };
+    let mut cursor = host.end;
+    while cursor < url.end && chars[cursor] != '/' && chars[cursor] != '?' && chars[cursor] != '#' {
+        cursor += 1;
+    }
+    parts.push(host);
+    let mut part_begin = cursor;
+    while cursor <= url.end {
+        if cursor == url.end || "/?#".contains(chars[cursor]) {
+            if cursor > part_begin {
+                parts.push(part_begin..cursor);
+            }
+            if cursor == url.end || chars[cursor] != '/' {
+                break;
+            }
+            part_begin = cursor + 1;
+        }
+        cursor += 1;
+    }
+    parts
+}
+
+// local@domain.tld
+fn scan_email(chars: &[char], begin: usize) -> Option<usize> {
+    let mut at = begin;
+    while at < chars.len()
+        && (chars[at].is_ascii_alphanumeric() || EMAIL_LOCAL_CHARS.contains(chars[at]))
+    {
+        at += 1;
+    }
+    if at == begin || chars.get(at) != Some(&'@') {
+        return None;
+    }
+    let mut end = at + 1;
+    while end < chars.len()
+        && (chars[end].is_ascii_alphanumeric() || chars[end] == '.' || chars[end] == '-')
+    {
+        end += 1;
+    }
+    while end > at + 1 && (chars[end - 1] == '.' || chars[end - 1] == '-') {
+        end -= 1;
+    }
+    let domain: String = chars[at + 1..end].iter().collect();
+    let tld = domain.rsplit_once('.')?.1;
+    if tld.len() < 2 || !tld.chars().all(|c| c.is_ascii_alphabetic()) {
+        return None;
+    }
+    Some(end)
+}
+
+// 192.168.1.1 | 2001:db8::1
+fn scan_ip(chars: &[char], begin: usize) -> Option<usize> {
+    let mut end = begin;
+    while end < chars.len() && (chars[end].is_ascii_digit() || chars[end] == '.') {
+        end += 1;
+    }
+    while end > begin && chars[end - 1] == '.' {
+        end -= 1;
+    }
+    let ipv4: String = chars[begin..end].iter().collect();
+    if ipv4.parse::<Ipv4Addr>().is_ok()
+        && !chars.get(end).is_some_and(|c| c.is_ascii_alphanumeric())
+    {
+        return Some(end);
+    }
+    let mut end = begin;
+    while end < chars.len()
+        && (chars[end].is_ascii_hexdigit() || chars[end] == ':' || chars[end] == '.')
+    {
+        end += 1;
+    }
+    while end > begin + 1
+        && (chars[end - 1] == '.' || (chars[end - 1] == ':' && chars[end - 2] != ':'))
+    {
+        end -= 1;
+    }
+    let ipv6: String = chars[begin..end].iter().collect();
+    if ipv6.matches(':').count() >= 2
+        && ipv6.parse::<Ipv6Addr>().is_ok()
+        && !chars.get(end).is_some_and(|c| c.is_ascii_alphanumeric())
+    {
+        return Some(end);
+    }
+    None
+}
+
+// mobile: +86 138-1234-5678 | 13812345678, landline: 010-12345678 | 0755-1234567
+fn scan_phone(chars: &[char], begin: usize) -> Option<usize> {
+    let mut cursor = begin;
+    for country_code in ["+86", "0086"] {
+        if starts_with(chars, begin, country_code) {
+            cursor += country_code.len();
+            if matches!(chars.get(cursor), Some('-') | Some(' ')) {
+                cursor += 1;
+            }
+            break;
+        }
+    }
+    let (end, digits, separators) = scan_phone_digits(chars, cursor);
+    if digits.is_empty() || chars.get(end).is_some_and(|c| c.is_ascii_alphanumeric()) {
+        return None;
+    }
+    let digit_at = |i: usize| digits.chars().nth(i).unwrap();
+    let is_mobile = digits.len() == 11
+        && digit_at(0) == '1'
+        && ('3'..='9').contains(&digit_at(1))
+        && separators.iter().all(|pos| *pos == 3 || *pos == 7);
+    let is_landline = cursor == begin
+        && digit_at(0) == '0'
+        && separators.len() == 1
+        && (separators[0] == 3 || separators[0] == 4)
+        && (7..=8).contains(&(digits.len() - separators[0]));
+    if is_mobile || is_landline {
+        return Some(end);
+    }
+    None
+}
+
+// digits with '-' or ' ' between them, separators are recorded by the digit count before them
+fn scan_phone_digits(chars: &[char], begin: usize) -> (usize, String, Vec<usize>) {
+    let mut digits = String::new();
+    let mut separators = Vec::new();
+    let mut end = begin;
+    while end < chars.len() {
+        let c = chars[end];
+        if c.is_ascii_digit() {
+            digits.push(c);
+        } else if (c == '-' || c == ' ')
+            && !digits.is_empty()
+            && chars.get(end + 1).is_some_and(|n| n.is_ascii_digit())
+        {
+            separators.push(digits.len());
+        } else {
+            break;
+        }
+        end += 1;
+    }
+    (end, digits, separators)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn chars(text: &str) -> Vec<char> {
+        text.chars().collect()
+    }
+
+    #[test]
+    fn test_scan_entity() {
+        let url = chars("https://github.com:443/blueshen/ik-rs?tab=1.");
+        assert_eq!(Some(url.len() - 1), scan_url(&url, 0));
+        assert_eq!(
+            vec![8..18, 23..31, 32..37],
+            url_parts(&url, 0..url.len() - 1)
+        );
+        assert_eq!(Some(14), scan_url(&chars("www.google.com"), 0));
+        assert_eq!(Some(20), scan_email(&chars("zhiyi.shen@gmail.com"), 0));
+        assert_eq!(None, scan_email(&chars("zhiyi@localhost"), 0));
+        assert_eq!(Some(11), scan_ip(&chars("192.168.1.1"), 0));
+        assert_eq!(None, scan_ip(&chars("192.168.1.256"), 0));
+        assert_eq!(Some(11), scan_ip(&chars("2001:db8::1"), 0));
+        assert_eq!(Some(11), scan_phone(&chars("13812345678"), 0));
+        assert_eq!(Some(17), scan_phone(&chars("+86 138-1234-5678"), 0));
+        assert_eq!(Some(12), scan_phone(&chars("010-12345678"), 0));
+        assert_eq!(None, scan_phone(&chars("12345678901"), 0));
+        assert_eq!(None, scan_phone(&chars("138123456789"), 0));
+    }
+}
@@ -3,22 +3,138 @@ use std::collections::{HashMap, LinkedList};
 use crate::core::char_util::{regularize_str, utf8_len, CharType};
 use crate::core::cjk_segmenter::CJKSegmenter;
 use crate::core::cn_quantifier_segmenter::CnQuantifierSegmenter;
+use crate::core::datetime_segmenter::DateTimeSegmenter;
+use crate::core::entity_segmenter::EntitySegmenter;
 use crate::core::ik_arbitrator::IKArbitrator;
-use crate::core::letter_segmentor::LetterSegmenter;
+use crate::core::letter_segmentor::{LetterSegmenter, LETTER_CONNECTOR, NUM_CONNECTOR};
 use crate::core::lexeme::{Lexeme, LexemeType};
 use crate::core::lexeme_path::LexemePath;
 use crate::core::ordered_linked_list::OrderedLinkedList;
+use crate::core::other_cjk_segmenter::OtherCjkSegmenter;
 use crate::core::segmentor::Segmenter;
+use crate::core::symbol_segmenter::SymbolSegmenter;
+use crate::core::t2s_converter::t2s;
 use crate::dict::dictionary::GLOBAL_DICT;
+use crate::filter::stop_word_filter::{StopWordFilter, StopWords};
 
 #[derive(Debug, Clone)]
 pub enum TokenMode {
     INDEX,
     SEARCH,
 }
+/// options of IKSegmenter
+/// ```
+/// use ik_rs::core::ik_segmenter::IKSegmenterBuilder;
+/// let ik = IKSegmenterBuilder::new().group_cjk_runs(true).build();
+/// ```
+#[derive(Debug, Clone)]
+pub struct IKSegmenterBuilder {
+    cjk_bigram: bool,
+    group_cjk_runs: bool,
+    entity_sub_tokens: bool,
+    emit_symbols: bool,
+    split_on_case_change: bool,
+    letter_connectors: Option<Vec<char>>,
+    num_connectors: Option<Vec<char>>,
+    traditional_to_simplified: bool,
+}
+
+impl Default for IKSegmenterBuilder {
+    fn default() -> Self {
+        IKSegmenterBuilder {
+            cjk_bigram: true,
+            group_cjk_runs: false,
+            entity_sub_tokens: true,
+            emit_symbols: false,
+            split_on_case_change: false,
+            letter_connectors: None,
+            num_connectors: None,
+            traditional_to_simplified: false,
+        }
+    }
+}
+
+impl IKSegmenterBuilder {
+    pub fn new() -> Self {
+        IKSegmenterBuilder::default()
+    }
+
+    /// emit bigrams for Hiragana/Katakana/Hangul instead of single chars, default on
+    pub fn cjk_bigram(mut self, cjk_bigram: bool) -> Self {
+        self.cjk_bigram = cjk_bigram;
+        self
+    }
+
+    /// keep a contiguous Katakana or Hangul run as one lexeme, default off
+    pub fn group_cjk_runs(mut self, group_cjk_runs: bool) -> Self {
+        self.group_cjk_runs = group_cjk_runs;
+        self
+    }
+
+    /// emit the lexemes inside urls, emails, ips and phone numbers besides the whole entity,
+    /// only kept in INDEX mode, default on
+    pub fn entity_sub_tokens(mut self, entity_sub_tokens: bool) -> Self {
+        self.entity_sub_tokens = entity_sub_tokens;
+        self
+    }
+
+    /// emit emoji, currency and math symbols as EMOJI/SYMBOL lexemes instead of dropping them, default off
+    pub fn emit_symbols(mut self, emit_symbols: bool) -> Self {
+        self.emit_symbols = emit_symbols;
+        self
+    }
+
+    /// split english words on case change besides the whole word: getUserName -> get | user | name, default off
+    pub fn split_on_case_change(mut self, split_on_case_change: bool) -> Self {
+        self.split_on_case_change = split_on_case_change;
+        self
+    }
+
+    /// chars joining letters and digits into one LETTER lexeme, default `letter_connectors` in ik.yml
+    pub fn letter_connectors(mut self, letter_connectors: &[char]) -> Self {
+        self.letter_connectors = Some(letter_connectors.to_vec());
+        self
+    }
+
+    /// chars inside an ARABIC lexeme, default `num_connectors` in ik.yml
+    pub fn num_connectors(mut self, num_connectors: &[char]) -> Self {
+        self.num_connectors = Some(num_connectors.to_vec());
+        self
+    }
+
+    /// convert Traditional to Simplified Chinese before dictionary lookup, lexeme texts are
+    /// simplified while offsets still point into the original text, default off
+    pub fn traditional_to_simplified(mut self, traditional_to_simplified: bool) -> Self {
+        self.traditional_to_simplified = traditional_to_simplified;
+        self
+    }
+
+    pub fn build(mut self) -> IKSegmenter {
+        if self.letter_connectors.is_none() || self.num_connectors.is_none() {
+            let lock_guard = {
+                cfg_if::cfg_if! {
+                    if #[cfg(feature="use-parking-lot")] {Some(GLOBAL_DICT.read())}
+                    else /*if #[cfg(feature="use-std-sync")]*/ {GLOBAL_DICT.read().ok()}
+                }
+            };
+            if let Some(dict) = lock_guard {
+                self.letter_connectors
+                    .get_or_insert_with(|| dict.letter_connectors());
+                self.num_connectors
+                    .get_or_insert_with(|| dict.num_connectors());
+            }
+        }
+        IKSegmenter {
+            arbitrator: IKArbitrator::default(),
+            options: self,
+        }
+    }
+}
+
 // ik main class
 pub struct IKSegmenter {
     arbitrator: IKArbitrator,
+    options: IKSegmenterBuilder,
 }
 
 unsafe impl Send for IKSegmenter {}
@@ -26,36 +142,86 @@ unsafe impl Sync for IKSegmenter {}
 
 impl IKSegmenter {
     pub fn new() -> Self {
-        let ik = IKSegmenter {
-            arbitrator: IKArbitrator::default(),
-        };
-        ik
+        IKSegmenterBuilder::default().build()
+    }
+
+    pub fn builder() -> IKSegmenterBuilder {
+        IKSegmenterBuilder::default()
     }
 
-    fn new_segmenters() -> Vec<Box<dyn Segmenter>> {
-        vec![
-            Box::new(LetterSegmenter::default()),
+    fn new_segmenters(&self, text: &str) -> Vec<Box<dyn Segmenter>> {
+        let mut letter_segmenter = LetterSegmenter::default().with_connectors(
+            self.options
+                .letter_connectors
+                .as_deref()
+                .unwrap_or(&LETTER_CONNECTOR),
+            self.options
+                .num_connectors
+                .as_deref()
+                .unwrap_or(&NUM_CONNECTOR),
+        );
+        if self.options.split_on_case_change {
+            letter_segmenter = letter_segmenter.split_on_case_change(text);
+        }
+        let mut segmenters: Vec<Box<dyn Segmenter>> = vec![
+            Box::new(letter_segmenter),
             Box::new(CnQuantifierSegmenter::default()),
             Box::new(CJKSegmenter::default()),
-        ]
+            Box::new(DateTimeSegmenter::default()),
+            Box::new(EntitySegmenter::new(self.options.entity_sub_tokens)),
+        ];
+        if self.options.emit_symbols {
+            segmenters.push(Box::new(SymbolSegmenter::default()));
+        }
+        if self.options.cjk_bigram || self.options.group_cjk_runs {
+            segmenters.push(Box::new(OtherCjkSegmenter::new(
+                self.options.cjk_bigram,
+                self.options.group_cjk_runs,
+            )));
+        }
+        segmenters
     }
 
+    /// segment and remove the default stop words
     pub fn tokenize(&self, text: &str, mode: TokenMode) -> Vec<Lexeme> {
-        let regular_str = regularize_str(text);
+        self.tokenize_with_stop_words(text, mode, &StopWords::Default)
+    }
+
+    /// segment and remove the given stop words, removed ones are left as position gaps
+    pub fn tokenize_with_stop_words(
+        &self,
+        text: &str,
+        mode: TokenMode,
+        stop_words: &StopWords,
+    ) -> Vec<Lexeme> {
+        StopWordFilter::filter_with(stop_words, self.segment(text, mode))
+    }
+
+    /// raw segmenter output, no token filter applied
+    pub fn segment(&self, text: &str, mode: TokenMode) -> Vec<Lexeme> {
+        let mut regular_str = regularize_str(text);
+        if self.options.traditional_to_simplified {
+            regular_str = t2s(&regular_str);
+        }
         let input = regular_str.as_str();
         let mut origin_lexemes = OrderedLinkedList::<Lexeme>::new();
-        let mut segmenters = IKSegmenter::new_segmenters();
+        let mut segmenters = self.new_segmenters(text);
         for (cursor, curr_char) in input.chars().enumerate() {
             let curr_char_type = CharType::from(curr_char);
             for segmenter in segmenters.iter_mut() {
                 segmenter.analyze(input, cursor, &curr_char_type, &mut origin_lexemes);
             }
         }
+        if !self.options.entity_sub_tokens {
+            origin_lexemes = remove_entity_sub_lexemes(origin_lexemes);
+        }
 
+        // Japanese & Korean bigrams overlap each other, like lucene CJKBigramFilter all of them
+        // are kept in both modes instead of being resolved by the arbitrator
+        let (origin_lexemes, other_cjk_lexemes) = split_other_cjk_lexemes(origin_lexemes);
         let mut path_map = self.arbitrator.process(&origin_lexemes, &mode);
-        let mut results = self.output_to_result(&mut path_map, input);
+        let mut results = self.output_to_result(&mut path_map, &other_cjk_lexemes, input);
         let mut final_results = Vec::with_capacity(results.len());
-        // remove stop word
         let mut result = results.pop_front();
         while let Some(ref mut result_value) = result {
             match &mode {
@@ -64,33 +230,29 @@ impl IKSegmenter {
                 }
                 _ => {}
             }
-
-            let lock_guard = {
-                cfg_if::cfg_if! {
-                    if #[cfg(feature="use-parking-lot")] {Some(GLOBAL_DICT.read())}
-                    else /*if #[cfg(feature="use-std-sync")]*/ {GLOBAL_DICT.read().map_or(None,|x|Some(x))}
-                }
-            };
-            if lock_guard.is_none()
-                || !lock_guard.is_some_and(|x| {
-                    x.is_stop_word(input, result_value.begin_pos(), result_value.len())
-                })
-            {
-                result_value.parse_lexeme_text(input);
-                final_results.push(result_value.clone())
-            }
+            result_value.parse_lexeme_text(input);
+            final_results.push(result_value.clone());
             result = results.pop_front();
         }
+        set_positions(&mut final_results);
+        for lexeme in final_results.iter_mut() {
+            if lexeme.lexeme_type() == &LexemeType::OtherCJK {
+                lexeme.set_position_length(1);
+            }
+        }
         final_results
     }
 
     fn output_to_result(
         &self,
         path_map: &mut HashMap<usize, LexemePath>,
+        other_cjk_lexemes: &[Lexeme],
         input: &str,
     ) -> LinkedList<Lexeme> {
         let mut results = LinkedList::new();
         let mut index = 0usize;
+        let mut other_cjk_index = 0usize;
+        let mut other_cjk_end = 0usize;
         let char_count = utf8_len(input);
         while index < char_count {
             let curr_char = input.chars().nth(index).unwrap();
@@ -118,6 +280,20 @@ impl IKSegmenter {
                         }
                     }
                 }
+            } else if let CharType::OtherCjk = cur_char_type {
+                while let Some(lexeme) = other_cjk_lexemes
+                    .get(other_cjk_index)
+                    .filter(|l| l.begin_pos() <= index)
+                {
+                    results.push_back(lexeme.clone());
+                    other_cjk_end = other_cjk_end.max(lexeme.end_pos());
+                    other_cjk_index += 1;
+                }
+                // the last char of a bigram run is covered already
+                if index >= other_cjk_end {
+                    self.add_single_lexeme(&mut results, &cur_char_type, index);
+                }
+                index += 1;
             } else {
                 self.add_single_lexeme(&mut results, &cur_char_type, index);
                 index += 1;
@@ -171,7 +347,9 @@ impl IKSegmenter {
             }
 
             match result.lexeme_type() {
-                LexemeType::CNUM if !results.is_empty() => {
+                LexemeType::CNUM | LexemeType::DECIMAL | LexemeType::FRACTION
+                    if !results.is_empty() =>
+                {
                     let mut append_ok = false;
                     let next_lexeme = results.front();
                     next_lexeme.map(|next| match next.lexeme_type() {
@@ -190,6 +368,67 @@ impl IKSegmenter {
     }
 }
 
+// lexemes of other segmenters inside an url | email | ip | phone
+fn remove_entity_sub_lexemes(lexemes: OrderedLinkedList<Lexeme>) -> OrderedLinkedList<Lexeme> {
+    let entities: Vec<(usize, usize)> = lexemes
+        .iter()
+        .filter(|l| {
+            matches!(
+                l.lexeme_type(),
+                LexemeType::URL | LexemeType::EMAIL | LexemeType::IP | LexemeType::PHONE
+            )
+        })
+        .map(|l| (l.begin_pos(), l.end_pos()))
+        .collect();
+    let mut kept = OrderedLinkedList::new();
+    for lexeme in lexemes.into_iter() {
+        let span = (lexeme.begin_pos(), lexeme.end_pos());
+        let inside = entities
+            .iter()
+            .any(|entity| *entity != span && entity.0 <= span.0 && span.1 <= entity.1);
+        if !inside {
+            kept.insert(lexeme);
+        }
+    }
+    kept
+}
+
+// (lexemes to arbitrate, OtherCJK bigrams & runs)
+fn split_other_cjk_lexemes(
+    lexemes: OrderedLinkedList<Lexeme>,
+) -> (OrderedLinkedList<Lexeme>, Vec<Lexeme>) {
+    let mut others = OrderedLinkedList::new();
+    let mut other_cjk_lexemes = Vec::new();
+    for lexeme in lexemes.into_iter() {
+        if lexeme.lexeme_type() == &LexemeType::OtherCJK {
+            other_cjk_lexemes.push(lexeme);
+        } else {
+            others.insert(lexeme);
+        }
+    }
+    (others, other_cjk_lexemes)
+}
+
+// every distinct begin offset is a position, a lexeme spans the positions starting inside it:
+// 中华人民(0) | 中华(0) | 华人(1) | 人民(2) -> position 0, 0, 1, 2 & length 3, 2, 2, 1
+fn set_positions(lexemes: &mut [Lexeme]) {
+    let mut starts: Vec<usize> = lexemes.iter().map(|l| l.begin_pos()).collect();
+    starts.sort_unstable();
+    starts.dedup();
+    let mut last_position: Option<usize> = None;
+    for lexeme in lexemes.iter_mut() {
+        let position = starts.partition_point(|start| *start < lexeme.begin_pos());
+        let end_position = starts.partition_point(|start| *start < lexeme.end_pos());
+        let increment = match last_position {
+            Some(last) => position.saturating_sub(last),
+            None => position + 1,
+        };
+        lexeme.set_position_increment(increment);
+        lexeme.set_position_length((end_position - position).max(1));
+        last_position = Some(position);
+    }
+}
+
 #[cfg(test)]
 mod test {
     use std::thread;
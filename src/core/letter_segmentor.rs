@@ -5,9 +5,10 @@ use crate::core::segmentor::Segmenter;
 
 const SEGMENTER_NAME: &str = "LETTER_SEGMENTER";
 
-const LETTER_CONNECTOR: [char; 7] = ['#', '&', '+', '-', '.', '@', '_'];
+// default connectors, configured by `letter_connectors` & `num_connectors` in ik.yml
+pub(crate) const LETTER_CONNECTOR: [char; 7] = ['#', '&', '+', '-', '.', '@', '_'];
 
-const NUM_CONNECTOR: [char; 2] = [',', '.'];
+pub(crate) const NUM_CONNECTOR: [char; 2] = [',', '.'];
 
 #[derive(Debug)]
 pub struct LetterSegmenter {
@@ -19,6 +20,12 @@ pub struct LetterSegmenter {
 
     arabic_start: Option<usize>,
     arabic_end: Option<usize>,
+
+    // uppercase flags of the original text, empty when not splitting on case change
+    upper_case: Vec<bool>,
+
+    letter_connectors: Vec<char>,
+    num_connectors: Vec<char>,
 }
 
 impl Segmenter for LetterSegmenter {
@@ -47,10 +54,27 @@ impl Default for LetterSegmenter {
             english_end: None,
             arabic_start: None,
             arabic_end: None,
+            upper_case: Vec::new(),
+            letter_connectors: LETTER_CONNECTOR.to_vec(),
+            num_connectors: NUM_CONNECTOR.to_vec(),
         }
     }
 }
 impl LetterSegmenter {
+    pub fn with_connectors(mut self, letter_connectors: &[char], num_connectors: &[char]) -> Self {
+        self.letter_connectors = letter_connectors.to_vec();
+        self.num_connectors = num_connectors.to_vec();
+        self
+    }
+
+    /// also split english words on case change, like lucene WordDelimiterGraphFilter
+    /// getUserName -> get | user | name, XMLHttp -> xml | http
+    /// letter/digit transitions and connectors are always split: iphone15 -> iphone | 15, get_user -> get | user
+    pub fn split_on_case_change(mut self, origin_text: &str) -> Self {
+        self.upper_case = origin_text.chars().map(|c| c.is_uppercase()).collect();
+        self
+    }
+
     /// mix letter
     /// windows2000 | zhiyi.shen@gmail.com
     fn process_mix_letter(
@@ -74,7 +98,8 @@ impl LetterSegmenter {
                 CharType::ARABIC | CharType::ENGLISH => {
                     self.end = Some(cursor);
                 }
-                CharType::USELESS if self.is_letter_connector(&curr_char) => {
+                // a connector may be a SYMBOL char too: '+' | '→'
+                _ if self.is_letter_connector(&curr_char) => {
                     self.end = Some(cursor);
                 }
                 _ => {
@@ -119,6 +144,7 @@ impl LetterSegmenter {
                     let new_lexeme =
                         Lexeme::new(start..(self.english_end.unwrap() + 1), LexemeType::ENGLISH);
                     origin_lexemes.insert(new_lexeme);
+                    self.process_case_change(start, self.english_end.unwrap() + 1, origin_lexemes);
                     self.reset_english_state();
                 }
             },
@@ -129,11 +155,38 @@ impl LetterSegmenter {
                 if end == (char_count - 1) {
                     let new_lexeme = Lexeme::new(start..end + 1, LexemeType::ENGLISH);
                     origin_lexemes.insert(new_lexeme);
+                    self.process_case_change(start, end + 1, origin_lexemes);
                     self.reset_english_state();
                 }
             });
     }
 
+    // camel case parts of an english word
+    fn process_case_change(
+        &self,
+        start: usize,
+        end: usize,
+        origin_lexemes: &mut OrderedLinkedList<Lexeme>,
+    ) {
+        if self.upper_case.len() < end {
+            return;
+        }
+        let upper = &self.upper_case;
+        let mut part_start = start;
+        for i in start + 1..end {
+            let lower_to_upper = !upper[i - 1] && upper[i];
+            // the last upper letter of an acronym starts a new word: XMLHttp -> XML | Http
+            let acronym_end = upper[i - 1] && upper[i] && i + 1 < end && !upper[i + 1];
+            if lower_to_upper || acronym_end {
+                origin_lexemes.insert(Lexeme::new(part_start..i, LexemeType::ENGLISH));
+                part_start = i;
+            }
+        }
+        if part_start > start {
+            origin_lexemes.insert(Lexeme::new(part_start..end, LexemeType::ENGLISH));
+        }
+    }
+
     // arabic
     fn process_arabic_letter(
         &mut self,
@@ -155,7 +208,7 @@ impl LetterSegmenter {
                 CharType::ARABIC => {
                     self.arabic_end = Some(cursor);
                 }
-                CharType::USELESS if self.is_num_connector(&curr_char) => {}
+                _ if self.is_num_connector(&curr_char) => {}
                 _ => {
                     let new_lexeme =
                         Lexeme::new(start..(self.arabic_end.unwrap() + 1), LexemeType::ARABIC);
@@ -189,10 +242,10 @@ impl LetterSegmenter {
     }
 
     fn is_letter_connector(&self, input: &char) -> bool {
-        LETTER_CONNECTOR.contains(input)
+        self.letter_connectors.contains(input)
     }
 
     fn is_num_connector(&self, input: &char) -> bool {
-        NUM_CONNECTOR.contains(input)
+        self.num_connectors.contains(input)
     }
 }
@@ -1,9 +1,15 @@
 use std::cmp::Ordering;
 use std::ops::Range;
+use std::str::FromStr;
+
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 use crate::core::char_util::utf8_slice;
+use crate::core::datetime_segmenter::parse_datetime;
+use crate::core::number_util::{parse_number, parse_number_prefix};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum LexemeType {
     UNKNOWN,
     ENGLISH,
@@ -15,6 +21,18 @@ pub enum LexemeType {
     CNUM,
     COUNT,
     CQUAN,
+    DECIMAL,
+    PERCENT,
+    FRACTION,
+    DATETIME,
+    URL,
+    EMAIL,
+    IP,
+    PHONE,
+    EMOJI,
+    SYMBOL,
+    PINYIN,
+    SYNONYM,
 }
 
 impl LexemeType {
@@ -29,17 +47,96 @@ impl LexemeType {
             LexemeType::COUNT => "COUNT",
             LexemeType::CNUM => "TYPE_CNUM",
             LexemeType::CQUAN => "TYPE_CQUAN",
+            LexemeType::DECIMAL => "TYPE_DECIMAL",
+            LexemeType::PERCENT => "TYPE_PERCENT",
+            LexemeType::FRACTION => "TYPE_FRACTION",
+            LexemeType::DATETIME => "DATETIME",
+            LexemeType::URL => "URL",
+            LexemeType::EMAIL => "EMAIL",
+            LexemeType::IP => "IP",
+            LexemeType::PHONE => "PHONE",
+            LexemeType::EMOJI => "EMOJI",
+            LexemeType::SYMBOL => "SYMBOL",
+            LexemeType::PINYIN => "PINYIN",
+            LexemeType::SYNONYM => "SYNONYM",
             _ => "UNKNOW",
         }
     }
 }
 
+impl FromStr for LexemeType {
+    type Err = String;
+
+    /// inverse of `as_str`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lexeme_type = match s {
+            "ENGLISH" => LexemeType::ENGLISH,
+            "ARABIC" => LexemeType::ARABIC,
+            "LETTER" => LexemeType::LETTER,
+            "CN_WORD" => LexemeType::CNWORD,
+            "CN_CHAR" => LexemeType::CNCHAR,
+            "OtherCjk" => LexemeType::OtherCJK,
+            "COUNT" => LexemeType::COUNT,
+            "TYPE_CNUM" => LexemeType::CNUM,
+            "TYPE_CQUAN" => LexemeType::CQUAN,
+            "TYPE_DECIMAL" => LexemeType::DECIMAL,
+            "TYPE_PERCENT" => LexemeType::PERCENT,
+            "TYPE_FRACTION" => LexemeType::FRACTION,
+            "DATETIME" => LexemeType::DATETIME,
+            "URL" => LexemeType::URL,
+            "EMAIL" => LexemeType::EMAIL,
+            "IP" => LexemeType::IP,
+            "PHONE" => LexemeType::PHONE,
+            "EMOJI" => LexemeType::EMOJI,
+            "SYMBOL" => LexemeType::SYMBOL,
+            "PINYIN" => LexemeType::PINYIN,
+            "SYNONYM" => LexemeType::SYNONYM,
+            "UNKNOW" => LexemeType::UNKNOWN,
+            _ => return Err(format!("unknown lexeme type: {}", s)),
+        };
+        Ok(lexeme_type)
+    }
+}
+
+/// serialized as the name of `as_str`: "CN_WORD"
+#[cfg(feature = "serialize")]
+impl Serialize for LexemeType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl<'de> Deserialize<'de> for LexemeType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        LexemeType::from_str(&name).map_err(serde::de::Error::custom)
+    }
+}
+
+/// normalized value parsed from lexeme text
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum LexemeValue {
+    /// 九百六十万 | 960万 -> 9600000, percentage and fraction as ratio: 百分之三十 -> 0.3
+    Number(f64),
+    /// ISO 8601 date and/or time: 2024年10月18日 -> 2024-10-18, 下午三点半 -> 15:30
+    DateTime(String),
+}
+
 #[derive(Debug)]
 pub struct Lexeme {
     offset: usize, // maybe use later, current default = 0
     pos: Range<usize>,
     lexeme_text: String,
     lexeme_type: LexemeType,
+    lexeme_value: Option<LexemeValue>,
+    position_increment: usize,
+    position_length: usize,
 }
 
 impl Clone for Lexeme {
@@ -49,6 +146,9 @@ impl Clone for Lexeme {
             pos: self.pos.clone(),
             lexeme_text: self.lexeme_text.clone(),
             lexeme_type: self.lexeme_type.clone(),
+            lexeme_value: self.lexeme_value.clone(),
+            position_increment: self.position_increment,
+            position_length: self.position_length,
         }
     }
 }
@@ -84,6 +184,9 @@ impl Lexeme {
             pos,
             lexeme_type,
             lexeme_text: String::from(""),
+            lexeme_value: None,
+            position_increment: 1,
+            position_length: 1,
         }
     }
 
@@ -107,9 +210,67 @@ impl Lexeme {
         &self.lexeme_text
     }
 
+    pub fn set_lexeme_type(&mut self, lexeme_type: LexemeType) {
+        self.lexeme_type = lexeme_type;
+    }
+
+    /// replace the text of a lexeme made by a filter, the value parsed from the old text is dropped
+    pub fn set_lexeme_text(&mut self, lexeme_text: String) {
+        self.lexeme_text = lexeme_text;
+        self.lexeme_value = None;
+    }
+
+    /// position distance to the previous output lexeme, 0 for lexemes stacked at the same position
+    pub fn position_increment(&self) -> usize {
+        self.position_increment
+    }
+
+    pub fn set_position_increment(&mut self, position_increment: usize) {
+        self.position_increment = position_increment;
+    }
+
+    /// number of positions spanned, > 1 for a word covering shorter words in INDEX mode
+    pub fn position_length(&self) -> usize {
+        self.position_length
+    }
+
+    pub fn set_position_length(&mut self, position_length: usize) {
+        self.position_length = position_length;
+    }
+
+    pub fn lexeme_value(&self) -> Option<&LexemeValue> {
+        self.lexeme_value.as_ref()
+    }
+
+    /// normalized ISO 8601 value of DATETIME lexemes
+    pub fn datetime_value(&self) -> Option<&str> {
+        match self.lexeme_value {
+            Some(LexemeValue::DateTime(ref datetime)) => Some(datetime),
+            _ => None,
+        }
+    }
+
+    /// numeric value of ARABIC/CNUM/CQUAN/DECIMAL/PERCENT/FRACTION lexemes
+    pub fn numeric_value(&self) -> Option<f64> {
+        match self.lexeme_value {
+            Some(LexemeValue::Number(n)) => Some(n),
+            _ => None,
+        }
+    }
+
     pub fn parse_lexeme_text(&mut self, input: &str) {
         let sub_text = utf8_slice(input, self.begin_pos(), self.end_pos());
         self.lexeme_text = sub_text.to_string();
+        self.lexeme_value = match self.lexeme_type {
+            LexemeType::ARABIC | LexemeType::CNUM | LexemeType::CQUAN => {
+                parse_number_prefix(&self.lexeme_text).map(LexemeValue::Number)
+            }
+            LexemeType::DECIMAL | LexemeType::PERCENT | LexemeType::FRACTION => {
+                parse_number(&self.lexeme_text).map(LexemeValue::Number)
+            }
+            LexemeType::DATETIME => parse_datetime(&self.lexeme_text).map(LexemeValue::DateTime),
+            _ => None,
+        };
     }
 
     pub fn append(&mut self, l: &Lexeme, lexeme_type: LexemeType) -> bool {
@@ -121,3 +282,105 @@ impl Lexeme {
         return false;
     }
 }
+
+// stable serialized form of Lexeme, new payloads are added as optional fields
+#[cfg(feature = "serialize")]
+#[derive(Serialize, Deserialize)]
+struct LexemeSchema {
+    text: String,
+    start_offset: usize,
+    end_offset: usize,
+    #[serde(rename = "type")]
+    lexeme_type: LexemeType,
+    #[serde(default = "default_position")]
+    position_increment: usize,
+    #[serde(default = "default_position")]
+    position_length: usize,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    value: Option<LexemeValue>,
+}
+
+#[cfg(feature = "serialize")]
+fn default_position() -> usize {
+    1
+}
+
+/// {"text":"中国","start_offset":0,"end_offset":2,"type":"CN_WORD","position_increment":1,"position_length":1}
+#[cfg(feature = "serialize")]
+impl Serialize for Lexeme {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        LexemeSchema {
+            text: self.lexeme_text.clone(),
+            start_offset: self.begin_pos(),
+            end_offset: self.end_pos(),
+            lexeme_type: self.lexeme_type.clone(),
+            position_increment: self.position_increment,
+            position_length: self.position_length,
+            value: self.lexeme_value.clone(),
+        }
+        .serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl<'de> Deserialize<'de> for Lexeme {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let schema = LexemeSchema::deserialize(deserializer)?;
+        if schema.end_offset < schema.start_offset {
+            return Err(serde::de::Error::custom(
+                "end_offset is less than start_offset",
+            ));
+        }
+        let mut lexeme = Lexeme::new(schema.start_offset..schema.end_offset, schema.lexeme_type);
+        lexeme.lexeme_text = schema.text;
+        lexeme.lexeme_value = schema.value;
+        lexeme.position_increment = schema.position_increment;
+        lexeme.position_length = schema.position_length;
+        Ok(lexeme)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_lexeme_type_from_str() {
+        for lexeme_type in [
+            LexemeType::CNWORD,
+            LexemeType::OtherCJK,
+            LexemeType::CQUAN,
+            LexemeType::UNKNOWN,
+        ] {
+            assert_eq!(Ok(lexeme_type.clone()), lexeme_type.as_str().parse());
+        }
+        assert!("CNWORD".parse::<LexemeType>().is_err());
+    }
+
+    #[cfg(feature = "serialize")]
+    #[test]
+    fn test_serde() {
+        let mut lexeme = Lexeme::new(0..3, LexemeType::CNUM);
+        lexeme.parse_lexeme_text("九百六十万");
+        let json = serde_json::to_string(&lexeme).unwrap();
+        assert_eq!(
+            r#"{"text":"九百六","start_offset":0,"end_offset":3,"type":"TYPE_CNUM","position_increment":1,"position_length":1,"value":{"number":960.0}}"#,
+            json
+        );
+        let parsed: Lexeme = serde_json::from_str(&json).unwrap();
+        assert_eq!(lexeme, parsed);
+        assert_eq!(Some(960.0), parsed.numeric_value());
+        assert_eq!("TYPE_CNUM", parsed.lexeme_type().as_str());
+
+        let parsed: Lexeme = serde_json::from_str(
+            r#"{"text":"中国","start_offset":0,"end_offset":2,"type":"CN_WORD"}"#,
+        )
+        .unwrap();
+        assert_eq!(1, parsed.position_increment());
+        assert_eq!(None, parsed.lexeme_value());
+        assert!(serde_json::from_str::<Lexeme>(
+            r#"{"text":"中国","start_offset":0,"end_offset":2,"type":"CNWORD"}"#
+        )
+        .is_err());
+    }
+}
@@ -1,10 +1,17 @@
+pub mod analyzer;
 pub(crate) mod char_util;
 pub(crate) mod cjk_segmenter;
 pub(crate) mod cn_quantifier_segmenter;
+pub(crate) mod datetime_segmenter;
+pub(crate) mod entity_segmenter;
 pub(crate) mod ik_arbitrator;
 pub mod ik_segmenter;
 pub(crate) mod letter_segmentor;
-pub(crate) mod lexeme;
+pub mod lexeme;
 pub(crate) mod lexeme_path;
+pub(crate) mod number_util;
 pub(crate) mod ordered_linked_list;
+pub(crate) mod other_cjk_segmenter;
 pub mod segmentor;
+pub(crate) mod symbol_segmenter;
+pub(crate) mod t2s_converter;
@@ -0,0 +1,239 @@
+// chinese numeral -> number
+// 九百六十万 | 960万 | 壹佰贰拾 | 廿三 | 二〇二四 | 一千二
+// 三点五 | 百分之三十 | 三分之一 | 3.5%
+
+pub(crate) const PERCENT_PREFIX: [char; 3] = ['百', '分', '之'];
+pub(crate) const FRACTION_INFIX: [char; 2] = ['分', '之'];
+pub(crate) const DECIMAL_POINT: char = '点';
+
+pub(crate) fn cn_digit(c: char) -> Option<f64> {
+    let digit = match c {
+        '零' | '〇' => 0,
+        '一' | '壹' => 1,
+        '二' | '贰' | '两' => 2,
+        '三' | '叁' => 3,
+        '四' | '肆' => 4,
+        '五' | '伍' => 5,
+        '六' | '陆' => 6,
+        '七' | '柒' => 7,
+        '八' | '捌' => 8,
+        '九' | '玖' => 9,
+        _ => return None,
+    };
+    Some(digit as f64)
+}
+
+fn cn_unit(c: char) -> Option<f64> {
+    match c {
+        '十' | '拾' => Some(10.0),
+        '百' | '佰' => Some(100.0),
+        '千' | '仟' => Some(1000.0),
+        _ => None,
+    }
+}
+
+fn cn_big_unit(c: char) -> Option<f64> {
+    match c {
+        '万' | '萬' => Some(1e4),
+        '亿' | '億' => Some(1e8),
+        '兆' => Some(1e12), // 万亿, not mega
+        _ => None,
+    }
+}
+
+pub(crate) fn is_cn_number_char(c: char) -> bool {
+    c == '廿'
+        || c == '卅'
+        || cn_digit(c).is_some()
+        || cn_unit(c).is_some()
+        || cn_big_unit(c).is_some()
+}
+
+fn is_number_char(c: char) -> bool {
+    c.is_ascii_digit() || c == ',' || c == '.' || is_cn_number_char(c)
+}
+
+/// parse a whole numeral, mixed arabic digits are allowed: "960万", "1.5亿"
+pub fn parse_cn_number(text: &str) -> Option<f64> {
+    let chars: Vec<char> = text.chars().collect();
+    if chars.is_empty() {
+        return None;
+    }
+    let mut total = 0f64; // finished sections, scaled by big units
+    let mut section = 0f64; // value below the current big unit
+    let mut number: Option<f64> = None; // pending digits
+    let mut last_unit = 0f64; // for shorthand like 一千二 = 1200
+    let mut after_zero = false;
+    let mut max_big_unit = 0f64;
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        if c.is_ascii_digit() {
+            let start = i;
+            while i < chars.len()
+                && (chars[i].is_ascii_digit() || chars[i] == ',' || chars[i] == '.')
+            {
+                i += 1;
+            }
+            let arabic: String = chars[start..i].iter().filter(|c| **c != ',').collect();
+            number = Some(arabic.parse::<f64>().ok()?);
+            continue;
+        }
+        if let Some(d) = cn_digit(c) {
+            number = match number {
+                // digit by digit: 二〇二四
+                Some(n) if !after_zero || n != 0.0 => Some(n * 10.0 + d),
+                _ => Some(d),
+            };
+            if d == 0.0 {
+                after_zero = true;
+                last_unit = 0.0;
+            }
+        } else if c == '廿' || c == '卅' {
+            section += if c == '廿' { 20.0 } else { 30.0 };
+            number = None;
+            last_unit = 10.0;
+        } else if let Some(unit) = cn_unit(c) {
+            section += number.unwrap_or(1.0) * unit;
+            number = None;
+            last_unit = unit;
+            after_zero = false;
+        } else if let Some(big_unit) = cn_big_unit(c) {
+            section += number.take().unwrap_or(0.0);
+            if section == 0.0 && total == 0.0 {
+                section = 1.0;
+            }
+            if big_unit > max_big_unit {
+                // 一万亿 = (1 * 万) * 亿
+                total = (total + section) * big_unit;
+                max_big_unit = big_unit;
+            } else {
+                total += section * big_unit;
+            }
+            section = 0.0;
+            last_unit = big_unit;
+            after_zero = false;
+        } else {
+            return None;
+        }
+        i += 1;
+    }
+    if let Some(n) = number {
+        if last_unit >= 10.0 && !after_zero && n < 10.0 {
+            section += n * last_unit / 10.0;
+        } else {
+            section += n;
+        }
+    }
+    Some(total + section)
+}
+
+// 二点七五 -> 2.75
+fn parse_decimal(text: &str) -> Option<f64> {
+    match text.split_once(DECIMAL_POINT) {
+        Some((integer, fraction)) => {
+            let mut decimal = String::from("0.");
+            for c in fraction.chars() {
+                match cn_digit(c) {
+                    Some(d) => decimal.push(char::from_digit(d as u32, 10)?),
+                    None if c.is_ascii_digit() => decimal.push(c),
+                    None => return None,
+                }
+            }
+            let fraction = if fraction.is_empty() {
+                0.0
+            } else {
+                decimal.parse::<f64>().ok()?
+            };
+            Some(parse_cn_number(integer)? + fraction)
+        }
+        None => parse_cn_number(text),
+    }
+}
+
+/// parse numeral, decimal, percentage or fraction, percentage and fraction are ratios:
+/// 百分之三十 -> 0.3, 三分之一 -> 0.333.., 3.5% -> 0.035
+pub fn parse_number(text: &str) -> Option<f64> {
+    let percent_prefix: String = PERCENT_PREFIX.iter().collect();
+    let fraction_infix: String = FRACTION_INFIX.iter().collect();
+    if let Some(percent) = text.strip_prefix(percent_prefix.as_str()) {
+        return Some(parse_decimal(percent)? / 100.0);
+    }
+    if let Some(percent) = text.strip_suffix('%') {
+        return Some(parse_decimal(percent)? / 100.0);
+    }
+    if let Some((denominator, numerator)) = text.split_once(fraction_infix.as_str()) {
+        let denominator = parse_decimal(denominator)?;
+        if denominator == 0.0 {
+            return None;
+        }
+        return Some(parse_decimal(numerator)? / denominator);
+    }
+    parse_decimal(text)
+}
+
+/// parse the leading numeral of a quantifier, "960万平方公里" -> 9600000, "三点五公斤" -> 3.5
+pub fn parse_number_prefix(text: &str) -> Option<f64> {
+    let chars: Vec<char> = text.chars().collect();
+    let mut end = 0;
+    while end < chars.len() {
+        if is_number_char(chars[end]) || chars[end] == DECIMAL_POINT || chars[end] == '%' {
+            end += 1;
+        } else if chars[end..].starts_with(&FRACTION_INFIX) {
+            end += FRACTION_INFIX.len();
+        } else {
+            break;
+        }
+    }
+    let prefix: String = chars[..end].iter().collect();
+    parse_number(&prefix)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_cn_number() {
+        assert_eq!(Some(9600000.0), parse_cn_number("九百六十万"));
+        assert_eq!(Some(9600000.0), parse_cn_number("960万"));
+        assert_eq!(Some(120.0), parse_cn_number("壹佰贰拾"));
+        assert_eq!(Some(23.0), parse_cn_number("廿三"));
+        assert_eq!(Some(30.0), parse_cn_number("卅"));
+        assert_eq!(Some(15.0), parse_cn_number("十五"));
+        assert_eq!(Some(2.0), parse_cn_number("两"));
+        assert_eq!(Some(2024.0), parse_cn_number("二〇二四"));
+        assert_eq!(Some(1200.0), parse_cn_number("一千二"));
+        assert_eq!(Some(23000.0), parse_cn_number("两万三"));
+        assert_eq!(Some(105.0), parse_cn_number("一百零五"));
+        assert_eq!(Some(100005.0), parse_cn_number("十万零五"));
+        assert_eq!(Some(1e12), parse_cn_number("一万亿"));
+        assert_eq!(Some(300020000.0), parse_cn_number("三亿零二万"));
+        assert_eq!(Some(1.5e8), parse_cn_number("1.5亿"));
+        assert_eq!(Some(1000.0), parse_cn_number("1,000"));
+        assert_eq!(Some(10000.0), parse_cn_number("万"));
+        assert_eq!(None, parse_cn_number("中国"));
+        assert_eq!(None, parse_cn_number(""));
+    }
+
+    #[test]
+    fn test_parse_number() {
+        assert_eq!(Some(3.5), parse_number("三点五"));
+        assert_eq!(Some(2.75), parse_number("二点七五"));
+        assert_eq!(Some(0.3), parse_number("百分之三十"));
+        assert_eq!(Some(0.035), parse_number("百分之三点五"));
+        assert_eq!(Some(0.25), parse_number("四分之一"));
+        assert_eq!(Some(0.035), parse_number("3.5%"));
+        assert_eq!(Some(12.0), parse_number("十二"));
+        assert_eq!(None, parse_number("零分之一"));
+    }
+
+    #[test]
+    fn test_parse_number_prefix() {
+        assert_eq!(Some(9600000.0), parse_number_prefix("960万平方公里"));
+        assert_eq!(Some(3.5), parse_number_prefix("三点五公斤"));
+        assert_eq!(Some(0.5), parse_number_prefix("二分之一杯"));
+        assert_eq!(Some(3.0), parse_number_prefix("三个"));
+        assert_eq!(None, parse_number_prefix("个"));
+    }
+}
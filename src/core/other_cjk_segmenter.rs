@@ -0,0 +1,82 @@
+use crate::core::char_util::{cjk_script, utf8_len, CharType, CjkScript};
+use crate::core::lexeme::{Lexeme, LexemeType};
+use crate::core::ordered_linked_list::OrderedLinkedList;
+use crate::core::segmentor::Segmenter;
+
+const SEGMENTER_NAME: &str = "OTHER_CJK_SEGMENTER";
+
+/// Japanese & Korean segmenter
+/// こんにちは -> こん | んに | にち | ちは (like lucene CJKBigramFilter)
+/// コンピュータ | 안녕하세요 -> one lexeme per run when `group_runs` is on
+#[derive(Debug)]
+pub struct OtherCjkSegmenter {
+    bigram: bool,
+    group_runs: bool,
+    run_start: Option<usize>,
+    run_script: Option<CjkScript>,
+}
+
+impl Segmenter for OtherCjkSegmenter {
+    fn analyze(
+        &mut self,
+        input: &str,
+        cursor: usize,
+        curr_char_type: &CharType,
+        origin_lexemes: &mut OrderedLinkedList<Lexeme>,
+    ) {
+        let curr_char = input.chars().nth(cursor).unwrap();
+        let script = match curr_char_type {
+            CharType::OtherCjk => cjk_script(curr_char),
+            _ => None,
+        };
+        match (self.run_start, script) {
+            (Some(_), Some(s)) if self.run_script == Some(s) => {
+                if self.bigram && !self.is_grouped(s) {
+                    let new_lexeme = Lexeme::new(cursor - 1..cursor + 1, LexemeType::OtherCJK);
+                    origin_lexemes.insert(new_lexeme);
+                }
+            }
+            _ => {
+                self.close_run(cursor, origin_lexemes);
+                if script.is_some() {
+                    self.run_start = Some(cursor);
+                    self.run_script = script;
+                }
+            }
+        }
+        if cursor == utf8_len(input) - 1 {
+            self.close_run(cursor + 1, origin_lexemes);
+        }
+    }
+
+    fn name(&self) -> &str {
+        SEGMENTER_NAME
+    }
+}
+
+impl OtherCjkSegmenter {
+    pub fn new(bigram: bool, group_runs: bool) -> Self {
+        OtherCjkSegmenter {
+            bigram,
+            group_runs,
+            run_start: None,
+            run_script: None,
+        }
+    }
+
+    fn is_grouped(&self, script: CjkScript) -> bool {
+        self.group_runs && script != CjkScript::Hiragana
+    }
+
+    // a single char run is left to `add_single_lexeme`
+    fn close_run(&mut self, end: usize, origin_lexemes: &mut OrderedLinkedList<Lexeme>) {
+        if let (Some(start), Some(script)) = (self.run_start, self.run_script) {
+            if self.is_grouped(script) && end - start > 1 {
+                let new_lexeme = Lexeme::new(start..end, LexemeType::OtherCJK);
+                origin_lexemes.insert(new_lexeme);
+            }
+        }
+        self.run_start = None;
+        self.run_script = None;
+    }
+}
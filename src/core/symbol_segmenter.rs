@@ -0,0 +1,44 @@
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::core::char_util::{is_emoji, utf8_from, utf8_len, CharType};
+use crate::core::lexeme::{Lexeme, LexemeType};
+use crate::core::ordered_linked_list::OrderedLinkedList;
+use crate::core::segmentor::Segmenter;
+
+const SEGMENTER_NAME: &str = "SYMBOL_SEGMENTER";
+
+/// emoji & symbol, one lexeme per grapheme cluster
+/// 😂 | 👍🏽 | 👨‍👩‍👧 | 🇨🇳 | ¥ | ≈
+#[derive(Default, Debug)]
+pub struct SymbolSegmenter {
+    covered_end: usize,
+}
+
+impl Segmenter for SymbolSegmenter {
+    fn analyze(
+        &mut self,
+        input: &str,
+        cursor: usize,
+        curr_char_type: &CharType,
+        origin_lexemes: &mut OrderedLinkedList<Lexeme>,
+    ) {
+        // inside a cluster, e.g. the skin tone of 👍🏽
+        if cursor < self.covered_end {
+            return;
+        }
+        if let CharType::SYMBOL = curr_char_type {
+            let cluster = utf8_from(input, cursor).graphemes(true).next().unwrap();
+            let lexeme_type = if cluster.chars().any(is_emoji) {
+                LexemeType::EMOJI
+            } else {
+                LexemeType::SYMBOL
+            };
+            self.covered_end = cursor + utf8_len(cluster);
+            origin_lexemes.insert(Lexeme::new(cursor..self.covered_end, lexeme_type));
+        }
+    }
+
+    fn name(&self) -> &str {
+        SEGMENTER_NAME
+    }
+}
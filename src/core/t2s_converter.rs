@@ -0,0 +1,67 @@
+use std::collections::HashMap;
+
+use once_cell::sync::Lazy;
+
+// OpenCC tables, only same-length mappings are bundled so char offsets never move
+const T2S_CHARACTERS: &str = include_str!("../../dict/opencc/TSCharacters.txt");
+const T2S_PHRASES: &str = include_str!("../../dict/opencc/TSPhrases.txt");
+
+static T2S_CHAR_MAP: Lazy<HashMap<char, char>> = Lazy::new(|| {
+    load_table(T2S_CHARACTERS)
+        .filter_map(|(t, s)| Some((t.chars().next()?, s.chars().next()?)))
+        .collect()
+});
+
+static T2S_PHRASE_MAP: Lazy<HashMap<Vec<char>, Vec<char>>> = Lazy::new(|| {
+    load_table(T2S_PHRASES)
+        .map(|(t, s)| (t.chars().collect(), s.chars().collect()))
+        .collect()
+});
+
+static MAX_PHRASE_LEN: Lazy<usize> =
+    Lazy::new(|| T2S_PHRASE_MAP.keys().map(|k| k.len()).max().unwrap_or(0));
+
+fn load_table(table: &str) -> impl Iterator<Item = (&str, &str)> {
+    table
+        .lines()
+        .filter(|line| !line.starts_with('#'))
+        .filter_map(|line| line.split_once('\t'))
+        .filter(|(t, s)| t.chars().count() == s.chars().count())
+}
+
+/// Traditional -> Simplified Chinese, phrases first by forward maximum matching:
+/// 中華人民共和國 -> 中华人民共和国, 乾清宮 -> 乾清宫 while 乾乾淨淨 -> 干干净净
+/// the output has exactly the same chars count as the input
+pub fn t2s(input: &str) -> String {
+    let chars: Vec<char> = input.chars().collect();
+    let mut output = String::with_capacity(input.len());
+    let mut cursor = 0;
+    'outer: while cursor < chars.len() {
+        let max_len = (*MAX_PHRASE_LEN).min(chars.len() - cursor);
+        for len in (2..=max_len).rev() {
+            if let Some(simplified) = T2S_PHRASE_MAP.get(&chars[cursor..cursor + len]) {
+                output.extend(simplified.iter());
+                cursor += len;
+                continue 'outer;
+            }
+        }
+        let c = chars[cursor];
+        output.push(*T2S_CHAR_MAP.get(&c).unwrap_or(&c));
+        cursor += 1;
+    }
+    output
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_t2s() {
+        assert_eq!("中华人民共和国", t2s("中華人民共和國"));
+        assert_eq!("干干净净", t2s("乾乾淨淨"));
+        assert_eq!("乾清宫", t2s("乾清宮"));
+        assert_eq!("ik分词器 v1.0", t2s("ik分詞器 v1.0"));
+        assert_eq!("", t2s(""));
+    }
+}
@@ -1,11 +1,15 @@
+use std::collections::HashMap;
 use std::fs::File;
-use std::io::{BufRead, BufReader};
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
 
 use once_cell;
-use once_cell::sync::Lazy;
+use once_cell::sync::{Lazy, OnceCell};
 
 use crate::config::configuration::Configuration;
 use crate::config::default_config::DefaultConfig;
+use crate::core::char_util::regularize_str;
 use crate::dict::hit::Hit;
 use crate::dict::trie::Trie;
 
@@ -17,60 +21,259 @@ cfg_if::cfg_if! {
     }
 }
 
+// ik.yml used by GLOBAL_DICT instead of the bundled one, see `set_global_config`
+static GLOBAL_CONFIG_PATH: OnceCell<PathBuf> = OnceCell::new();
+
+// dictionary given to `set_global_dictionary`, taken by GLOBAL_DICT on first use
+static PRELOADED_DICT: Mutex<Option<Dictionary>> = Mutex::new(None);
+
 pub static GLOBAL_DICT: Lazy<RwLock<Dictionary>> = Lazy::new(|| {
-    let mut dict = Dictionary::new();
+    if let Some(dict) = PRELOADED_DICT.lock().ok().and_then(|mut dict| dict.take()) {
+        return RwLock::new(dict);
+    }
+    let mut dict = match GLOBAL_CONFIG_PATH.get() {
+        Some(config_path) => match DefaultConfig::from_file(config_path) {
+            Ok(config) => Dictionary::with_config(Box::new(config)),
+            Err(e) => panic!("{}", e),
+        },
+        None => Dictionary::new(),
+    };
     if !dict.init() {
         panic!("dict init fatal error")
     }
     RwLock::new(dict)
 });
 
-fn load(dict: &mut Trie, file_path: &str) -> bool {
+/// load GLOBAL_DICT from another config file, must be called before the first segmentation.
+/// returns false when the config file is not readable or GLOBAL_DICT is already loaded
+pub fn set_global_config(config_path: &Path) -> bool {
+    if Lazy::get(&GLOBAL_DICT).is_some() {
+        return false;
+    }
+    if let Err(e) = DefaultConfig::from_file(config_path) {
+        log::error!("{}", e);
+        return false;
+    }
+    GLOBAL_CONFIG_PATH.set(config_path.to_path_buf()).is_ok()
+}
+
+/// config file given to `set_global_config`, None for the bundled ik.yml
+pub fn global_config_path() -> Option<&'static Path> {
+    GLOBAL_CONFIG_PATH.get().map(|path| path.as_path())
+}
+
+/// use an in-memory dictionary for GLOBAL_DICT, must be called before the first segmentation.
+/// it wins over `set_global_config`, returns false when GLOBAL_DICT is already loaded
+pub fn set_global_dictionary(dict: Dictionary) -> bool {
+    if Lazy::get(&GLOBAL_DICT).is_some() {
+        return false;
+    }
+    match PRELOADED_DICT.lock() {
+        Ok(mut preloaded) if preloaded.is_none() => {
+            *preloaded = Some(dict);
+            true
+        }
+        _ => false,
+    }
+}
+
+fn load(dict: &mut Trie, file_path: &str, source: u32) -> bool {
     let open_file = File::open(file_path);
     match open_file {
         Ok(file) => {
-            let reader = BufReader::new(file);
-            for line in reader.lines() {
-                match line {
-                    Ok(word) => {
-                        dict.insert(&word.trim());
-                    }
-                    Err(e) => {
-                        panic!("read dict:{} error:{}", file_path, e);
-                    }
-                }
+            if let Err(e) = load_lines(dict, BufReader::new(file), file_path, source) {
+                panic!("{}", e);
             }
-            log::debug!("after load dict:{}, size = {}", file_path, dict.size());
-            return true;
+            true
         }
         Err(e) => {
             panic!("open dict:{} error:{}", file_path, e);
         }
     }
 }
+
+fn load_lines(
+    dict: &mut Trie,
+    reader: impl BufRead,
+    name: &str,
+    source: u32,
+) -> Result<(), String> {
+    for line in reader.lines() {
+        match line {
+            Ok(word) => {
+                dict.insert_with_source(word.trim(), source);
+            }
+            Err(e) => {
+                return Err(format!("read dict:{} error:{}", name, e));
+            }
+        }
+    }
+    log::debug!("after load dict:{}, size = {}", name, dict.size());
+    Ok(())
+}
+
+/// word dictionaries of a `Dictionary`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DictType {
+    Main,
+    Quantifier,
+    StopWord,
+}
+
+// source of words added by `add_words`
+const RUNTIME_SOURCE: &str = "<runtime>";
+
 /// Dictionary Manager
 pub struct Dictionary {
     main_dict: Trie,
     stop_word_dict: Trie,
     quantifier_dict: Trie,
+    synonym_dict: HashMap<String, Vec<String>>,
+    max_synonym_len: usize,
+    // files or buffers words are loaded from, indexed by the source id in tries
+    sources: Vec<(DictType, String)>,
     cfg: Box<dyn Configuration>,
 }
 
 unsafe impl Sync for Dictionary {}
 unsafe impl Send for Dictionary {}
 
+impl Default for Dictionary {
+    fn default() -> Self {
+        Dictionary::new()
+    }
+}
+
 impl Dictionary {
     pub fn new() -> Self {
+        Dictionary::with_config(Box::new(DefaultConfig::new()))
+    }
+
+    /// dictionaries read from memory instead of the files in ik.yml, for targets without a
+    /// file system like wasm. buffers hold UTF-8 lines in the format of the .dic files,
+    /// connectors are the defaults
+    pub fn from_bytes(
+        main_dicts: &[&[u8]],
+        quantifier_dict: &[u8],
+        stop_word_dicts: &[&[u8]],
+        synonym_dicts: &[&[u8]],
+    ) -> Result<Self, String> {
+        let mut dict = Dictionary::with_config(Box::new(DefaultConfig::default()));
+        for (i, bytes) in main_dicts.iter().enumerate() {
+            dict.load_bytes(DictType::Main, bytes, &format!("main_dicts[{}]", i))?;
+        }
+        dict.load_bytes(DictType::Quantifier, quantifier_dict, "quantifier_dict")?;
+        for (i, bytes) in stop_word_dicts.iter().enumerate() {
+            dict.load_bytes(
+                DictType::StopWord,
+                bytes,
+                &format!("stop_word_dicts[{}]", i),
+            )?;
+        }
+        for (i, bytes) in synonym_dicts.iter().enumerate() {
+            dict.load_synonym_lines(*bytes, &format!("synonym_dicts[{}]", i))?;
+        }
+        Ok(dict)
+    }
+
+    fn with_config(cfg: Box<dyn Configuration>) -> Self {
         Dictionary {
             main_dict: Trie::new(),
             stop_word_dict: Trie::new(),
             quantifier_dict: Trie::new(),
-            cfg: Box::new(DefaultConfig::new()),
+            synonym_dict: HashMap::new(),
+            max_synonym_len: 0,
+            sources: vec![(DictType::Main, RUNTIME_SOURCE.to_string())],
+            cfg,
+        }
+    }
+
+    fn load_bytes(&mut self, dict_type: DictType, bytes: &[u8], name: &str) -> Result<(), String> {
+        let source = self.add_source(dict_type, name);
+        load_lines(self.dict_mut(dict_type), bytes, name, source)
+    }
+
+    fn add_source(&mut self, dict_type: DictType, name: &str) -> u32 {
+        self.sources.push((dict_type, name.to_string()));
+        (self.sources.len() - 1) as u32
+    }
+
+    fn dict(&self, dict_type: DictType) -> &Trie {
+        match dict_type {
+            DictType::Main => &self.main_dict,
+            DictType::Quantifier => &self.quantifier_dict,
+            DictType::StopWord => &self.stop_word_dict,
+        }
+    }
+
+    fn dict_mut(&mut self, dict_type: DictType) -> &mut Trie {
+        match dict_type {
+            DictType::Main => &mut self.main_dict,
+            DictType::Quantifier => &mut self.quantifier_dict,
+            DictType::StopWord => &mut self.stop_word_dict,
         }
     }
 
+    pub fn contains(&self, dict_type: DictType, word: &str) -> bool {
+        self.dict(dict_type).exist(word)
+    }
+
+    /// file or buffer name a word is loaded from, `<runtime>` for `add_words`.
+    /// a word in several files comes from the first one loaded
+    pub fn source(&self, dict_type: DictType, word: &str) -> Option<&str> {
+        self.dict(dict_type)
+            .source(word)
+            .map(|source| self.sources[source as usize].1.as_str())
+    }
+
+    /// words in char order
+    pub fn words(&self, dict_type: DictType) -> impl Iterator<Item = String> + '_ {
+        self.dict(dict_type).iter()
+    }
+
+    /// words starting with `prefix` in char order
+    pub fn words_with_prefix<'a>(
+        &'a self,
+        dict_type: DictType,
+        prefix: &str,
+    ) -> impl Iterator<Item = String> + 'a {
+        self.dict(dict_type).iter_prefix(prefix)
+    }
+
+    pub fn count(&self, dict_type: DictType) -> usize {
+        self.dict(dict_type).size()
+    }
+
+    /// number of words coming from each source, in loading order
+    pub fn source_counts(&self, dict_type: DictType) -> Vec<(&str, usize)> {
+        let counts = self.dict(dict_type).source_counts();
+        self.sources
+            .iter()
+            .enumerate()
+            .filter(|(_, (source_type, _))| *source_type == dict_type)
+            .map(|(source, (_, name))| {
+                let count = counts.get(&(source as u32)).copied().unwrap_or(0);
+                (name.as_str(), count)
+            })
+            .collect()
+    }
+
+    /// write the effective dictionary in .dic format, one word per line in char order,
+    /// returns the number of words
+    pub fn export(&self, dict_type: DictType, writer: &mut impl Write) -> io::Result<usize> {
+        let mut count = 0;
+        for word in self.words(dict_type) {
+            writeln!(writer, "{}", word)?;
+            count += 1;
+        }
+        Ok(count)
+    }
+
     fn init(&mut self) -> bool {
-        self.load_main_dict() && self.load_stop_word_dict() && self.load_quantifier_dict()
+        self.load_main_dict()
+            && self.load_stop_word_dict()
+            && self.load_quantifier_dict()
+            && self.load_synonym_dict()
     }
 
     #[allow(dead_code)]
@@ -87,6 +290,24 @@ impl Dictionary {
         }
     }
 
+    /// add a group of equivalent words: ["土豆", "马铃薯", "洋芋"]
+    pub fn add_synonyms(&mut self, words: Vec<&str>) {
+        let words: Vec<String> = words
+            .iter()
+            .map(|word| regularize_str(word.trim()))
+            .filter(|word| !word.is_empty())
+            .collect();
+        for word in words.iter() {
+            let synonyms = self.synonym_dict.entry(word.clone()).or_default();
+            for synonym in words.iter() {
+                if synonym != word && !synonyms.contains(synonym) {
+                    synonyms.push(synonym.clone());
+                }
+            }
+            self.max_synonym_len = self.max_synonym_len.max(word.chars().count());
+        }
+    }
+
     #[allow(dead_code)]
     pub fn match_in_main_dict(&self, word: &str) -> Vec<Hit> {
         self.main_dict.match_word(word)
@@ -118,9 +339,27 @@ impl Dictionary {
         false
     }
 
+    pub fn synonyms(&self, word: &str) -> Option<&Vec<String>> {
+        self.synonym_dict.get(word)
+    }
+
+    /// chars count of the longest word having synonyms
+    pub fn max_synonym_len(&self) -> usize {
+        self.max_synonym_len
+    }
+
+    pub fn letter_connectors(&self) -> Vec<char> {
+        self.cfg.get_letter_connectors()
+    }
+
+    pub fn num_connectors(&self) -> Vec<char> {
+        self.cfg.get_num_connectors()
+    }
+
     fn load_main_dict(&mut self) -> bool {
         let file_path = self.cfg.get_main_dictionary();
-        if load(&mut self.main_dict, file_path.as_str()) {
+        let source = self.add_source(DictType::Main, &file_path);
+        if load(&mut self.main_dict, file_path.as_str(), source) {
             return self.load_ext_dict();
         }
         false
@@ -130,7 +369,8 @@ impl Dictionary {
         let ext_dict_files = self.cfg.get_ext_dictionaries();
         let mut ret = true;
         for ext_dict_file in ext_dict_files.iter() {
-            if !load(&mut self.main_dict, ext_dict_file.as_str()) {
+            let source = self.add_source(DictType::Main, ext_dict_file);
+            if !load(&mut self.main_dict, ext_dict_file.as_str(), source) {
                 ret = false;
             }
         }
@@ -141,7 +381,8 @@ impl Dictionary {
         let ext_stop_word_dict_files = self.cfg.get_ext_stop_word_dictionaries();
         let mut ret = true;
         for stop_file in ext_stop_word_dict_files.iter() {
-            if !load(&mut self.stop_word_dict, stop_file.as_str()) {
+            let source = self.add_source(DictType::StopWord, stop_file);
+            if !load(&mut self.stop_word_dict, stop_file.as_str(), source) {
                 ret = false;
             }
         }
@@ -150,7 +391,44 @@ impl Dictionary {
 
     fn load_quantifier_dict(&mut self) -> bool {
         let file_path = self.cfg.get_quantifier_dictionary();
-        load(&mut self.quantifier_dict, file_path.as_str())
+        let source = self.add_source(DictType::Quantifier, &file_path);
+        load(&mut self.quantifier_dict, file_path.as_str(), source)
+    }
+
+    // one group of equivalent words per line: 土豆,马铃薯,洋芋
+    fn load_synonym_dict(&mut self) -> bool {
+        for file_path in self.cfg.get_synonym_dictionaries().iter() {
+            let file = match File::open(file_path) {
+                Ok(file) => file,
+                Err(e) => panic!("open dict:{} error:{}", file_path, e),
+            };
+            if let Err(e) = self.load_synonym_lines(BufReader::new(file), file_path) {
+                panic!("{}", e);
+            }
+        }
+        true
+    }
+
+    fn load_synonym_lines(&mut self, reader: impl BufRead, name: &str) -> Result<(), String> {
+        for line in reader.lines() {
+            match line {
+                Ok(line) => {
+                    let line = line.trim();
+                    if !line.is_empty() && !line.starts_with('#') {
+                        self.add_synonyms(line.split(',').collect());
+                    }
+                }
+                Err(e) => {
+                    return Err(format!("read dict:{} error:{}", name, e));
+                }
+            }
+        }
+        log::debug!(
+            "after load dict:{}, size = {}",
+            name,
+            self.synonym_dict.len()
+        );
+        Ok(())
     }
 }
 
@@ -174,6 +452,98 @@ mod test {
             let hits = dictionary.match_in_main_dict(word);
             assert_eq!(true, hits.len() > 0);
         }
+        assert_eq!(
+            Some(&vec!["马铃薯".to_string(), "洋芋".to_string()]),
+            dictionary.synonyms("土豆")
+        );
+        assert!(dictionary
+            .source(DictType::Main, "一夕之间")
+            .unwrap()
+            .ends_with("dict/main2012.dic"));
+        assert_eq!(
+            Some("<runtime>"),
+            dictionary.source(DictType::Main, "blues")
+        );
+        dictionary.add_synonyms(vec!["ABC", "abc", "艾比西"]);
+        assert_eq!(
+            Some(&vec!["艾比西".to_string()]),
+            dictionary.synonyms("abc")
+        );
+    }
+
+    #[test]
+    fn test_from_bytes() {
+        let dictionary = Dictionary::from_bytes(
+            &["张三\n李四\n".as_bytes(), "王五".as_bytes()],
+            "个\n".as_bytes(),
+            &["的".as_bytes()],
+            &["# comment\n土豆,马铃薯".as_bytes()],
+        )
+        .unwrap();
+        let is_match = |hits: Vec<Hit>| hits.iter().any(|hit| hit.is_match());
+        for word in ["张三", "李四", "王五"] {
+            assert!(is_match(dictionary.match_in_main_dict(word)));
+        }
+        assert!(!is_match(dictionary.match_in_main_dict("一夕之间")));
+        assert!(is_match(dictionary.match_in_quantifier_dict("个", 0, 1)));
+        assert!(dictionary.is_stop_word("的", 0, 1));
+        assert_eq!(
+            Some(&vec!["马铃薯".to_string()]),
+            dictionary.synonyms("土豆")
+        );
+        assert_eq!(
+            DefaultConfig::default().get_letter_connectors(),
+            dictionary.letter_connectors()
+        );
+        let invalid: &[u8] = &[0xff, 0xfe];
+        assert!(Dictionary::from_bytes(&[invalid], &[], &[], &[]).is_err());
+    }
+
+    #[test]
+    fn test_dictionary_management() {
+        let mut dictionary = Dictionary::from_bytes(
+            &["张三\n张三丰\n李四\n".as_bytes(), "李四\n王五\n".as_bytes()],
+            "个\n".as_bytes(),
+            &[],
+            &[],
+        )
+        .unwrap();
+        assert!(dictionary.contains(DictType::Main, "李四"));
+        assert!(!dictionary.contains(DictType::Main, "张"));
+        assert!(!dictionary.contains(DictType::Quantifier, "李四"));
+        assert_eq!(
+            Some("main_dicts[0]"),
+            dictionary.source(DictType::Main, "李四")
+        );
+        assert_eq!(
+            Some("main_dicts[1]"),
+            dictionary.source(DictType::Main, "王五")
+        );
+        assert_eq!(None, dictionary.source(DictType::Main, "赵六"));
+        assert_eq!(
+            vec!["张三", "张三丰"],
+            dictionary
+                .words_with_prefix(DictType::Main, "张")
+                .collect::<Vec<String>>()
+        );
+        assert_eq!(1, dictionary.count(DictType::Quantifier));
+
+        dictionary.add_words(vec!["赵六"]);
+        dictionary.disable_words(vec!["张三丰"]);
+        assert_eq!(
+            vec![("<runtime>", 1), ("main_dicts[0]", 2), ("main_dicts[1]", 1)],
+            dictionary.source_counts(DictType::Main)
+        );
+        assert_eq!(
+            vec![("quantifier_dict", 1)],
+            dictionary.source_counts(DictType::Quantifier)
+        );
+        let mut exported = Vec::new();
+        assert_eq!(4, dictionary.export(DictType::Main, &mut exported).unwrap());
+        assert_eq!(
+            "张三\n李四\n王五\n赵六\n",
+            String::from_utf8(exported).unwrap()
+        );
     }
 
     #[test]
@@ -1,3 +1,3 @@
-pub(crate) mod dictionary;
+pub mod dictionary;
 pub(crate) mod hit;
 pub mod trie;
@@ -7,6 +7,8 @@ use crate::dict::hit::Hit;
 pub struct TrieNode {
     value: Option<char>,
     final_state: bool,
+    // id of the dictionary source a final state comes from
+    source: u32,
     child_nodes: HashMap<char, TrieNode>,
 }
 
@@ -27,6 +29,7 @@ impl TrieNode {
         TrieNode {
             value: Some(c),
             final_state,
+            source: 0,
             child_nodes: HashMap::new(),
         }
     }
@@ -35,6 +38,7 @@ impl TrieNode {
         TrieNode {
             value: None,
             final_state: false,
+            source: 0,
             child_nodes: HashMap::new(),
         }
     }
@@ -76,39 +80,60 @@ impl TrieNode {
         return current_node.is_final_state();
     }
 
-    pub fn delete(&mut self, string_val: &str) -> usize {
-        let mut current_node = self;
-        let mut del_success = 0usize;
-        for (_, curr_char) in string_val.chars().enumerate() {
-            if !current_node.child_nodes.contains_key(&curr_char) {
-                return del_success;
+    /// unmark a word and remove the branch left without words,
+    /// returns whether the word was in the trie
+    pub fn delete(&mut self, string_val: &str) -> bool {
+        let chars: Vec<char> = string_val.chars().collect();
+        self.delete_chars(&chars)
+    }
+
+    fn delete_chars(&mut self, chars: &[char]) -> bool {
+        match chars.split_first() {
+            None => {
+                let deleted = self.final_state;
+                self.final_state = false;
+                deleted
+            }
+            Some((curr_char, rest)) => {
+                let child_node = match self.child_nodes.get_mut(curr_char) {
+                    Some(child_node) => child_node,
+                    None => return false,
+                };
+                let deleted = child_node.delete_chars(rest);
+                if deleted && !child_node.is_final_state() && !child_node.has_childs() {
+                    self.child_nodes.remove(curr_char);
+                }
+                deleted
             }
-            current_node = current_node.child_nodes.get_mut(&curr_char).unwrap();
         }
-        current_node.final_state = false;
-        del_success += 1;
-        del_success
     }
 
-    pub fn insert(&mut self, string_val: &str) -> usize {
+    /// mark a word, creating the missing nodes of it, returns whether the word is new
+    pub fn insert(&mut self, string_val: &str, source: u32) -> bool {
+        if string_val.is_empty() {
+            return false;
+        }
         let mut current_node = self;
-        let char_count = string_val.chars().count();
-        let mut final_state = false;
-        let mut insert_success = 0usize;
-        for (counter, curr_char) in string_val.chars().enumerate() {
-            if !current_node.child_nodes.contains_key(&curr_char) {
-                if counter == char_count - 1 {
-                    final_state = true;
-                }
-                if current_node.add_child(curr_char, final_state) {
-                    if final_state {
-                        insert_success += 1;
-                    }
-                }
-            }
-            current_node = current_node.child_nodes.get_mut(&curr_char).unwrap();
+        for curr_char in string_val.chars() {
+            current_node = current_node
+                .child_nodes
+                .entry(curr_char)
+                .or_insert_with(|| TrieNode::new(curr_char, false));
+        }
+        if current_node.is_final_state() {
+            return false;
         }
-        insert_success
+        current_node.final_state = true;
+        current_node.source = source;
+        true
+    }
+
+    fn find(&self, string_val: &str) -> Option<&TrieNode> {
+        let mut current_node = self;
+        for curr_char in string_val.chars() {
+            current_node = current_node.child_nodes.get(&curr_char)?;
+        }
+        Some(current_node)
     }
 
     pub fn match_with_offset(&self, string_val: &str, offset: usize, length: usize) -> Vec<Hit> {
@@ -162,23 +187,70 @@ impl Trie {
         }
     }
 
+    /// returns whether the word is new
     pub fn insert(&mut self, string_val: &str) -> bool {
-        let current_node = &mut self.root;
-        let insert_num = current_node.insert(string_val);
-        self.size += insert_num;
-        true
+        self.insert_with_source(string_val, 0)
+    }
+
+    /// insert a word and remember the dictionary `source` id it comes from,
+    /// the source of a word already in the trie is kept
+    pub fn insert_with_source(&mut self, string_val: &str, source: u32) -> bool {
+        let inserted = self.root.insert(string_val, source);
+        if inserted {
+            self.size += 1;
+        }
+        inserted
+    }
+
+    /// source id given to `insert_with_source`, None if the word is not in the trie
+    pub fn source(&self, string_val: &str) -> Option<u32> {
+        self.root
+            .find(string_val)
+            .filter(|node| node.is_final_state())
+            .map(|node| node.source)
+    }
+
+    /// all words in char order
+    pub fn iter(&self) -> Iter<'_> {
+        Iter {
+            stack: vec![(String::new(), &self.root)],
+        }
+    }
+
+    /// words starting with `prefix` in char order, the prefix itself included
+    pub fn iter_prefix(&self, prefix: &str) -> Iter<'_> {
+        Iter {
+            stack: self
+                .root
+                .find(prefix)
+                .map(|node| (prefix.to_string(), node))
+                .into_iter()
+                .collect(),
+        }
+    }
+
+    /// number of words per source id
+    pub fn source_counts(&self) -> HashMap<u32, usize> {
+        let mut counts = HashMap::new();
+        let mut iter = self.iter();
+        while let Some((_, node)) = iter.next_node() {
+            *counts.entry(node.source).or_insert(0) += 1;
+        }
+        counts
     }
 
     pub fn size(&self) -> usize {
         self.size
     }
 
+    /// returns whether the word was in the trie
     #[allow(dead_code)]
     pub fn delete(&mut self, string_val: &str) -> bool {
-        let current_node = &mut self.root;
-        let del_num = current_node.delete(string_val);
-        self.size -= del_num;
-        true
+        let deleted = self.root.delete(string_val);
+        if deleted {
+            self.size -= 1;
+        }
+        deleted
     }
 
     #[allow(dead_code)]
@@ -205,11 +277,53 @@ impl Trie {
     }
 }
 
+/// depth first iterator over the words of a `Trie`
+pub struct Iter<'a> {
+    // nodes to visit with the word leading to them
+    stack: Vec<(String, &'a TrieNode)>,
+}
+
+impl<'a> Iter<'a> {
+    fn next_node(&mut self) -> Option<(String, &'a TrieNode)> {
+        while let Some((word, node)) = self.stack.pop() {
+            let mut child_nodes: Vec<(&char, &TrieNode)> = node.child_nodes.iter().collect();
+            child_nodes.sort_unstable_by_key(|(c, _)| **c);
+            for (c, child_node) in child_nodes.into_iter().rev() {
+                let mut child_word = word.clone();
+                child_word.push(*c);
+                self.stack.push((child_word, child_node));
+            }
+            if node.is_final_state() {
+                return Some((word, node));
+            }
+        }
+        None
+    }
+}
+
+impl Iterator for Iter<'_> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_node().map(|(word, _)| word)
+    }
+}
+
 #[cfg(test)]
 mod test {
+    use std::collections::HashSet;
     use std::thread;
 
+    use proptest::prelude::*;
+
     use super::*;
+
+    fn node_count(node: &TrieNode) -> usize {
+        node.child_nodes
+            .values()
+            .map(|child| 1 + node_count(child))
+            .sum()
+    }
     #[test]
     fn trie_exist() {
         let mut trie = Trie::new();
@@ -244,6 +358,156 @@ mod test {
         }
     }
 
+    #[test]
+    fn trie_iter() {
+        let mut trie = Trie::new();
+        for word in ["Tea", "Test", "Brown", "Background", "申艳超", "blues小站"] {
+            trie.insert(word);
+        }
+        assert_eq!(
+            vec!["Background", "Brown", "Tea", "Test", "blues小站", "申艳超"],
+            trie.iter().collect::<Vec<String>>()
+        );
+        assert_eq!(
+            vec!["Tea", "Test"],
+            trie.iter_prefix("Te").collect::<Vec<String>>()
+        );
+        assert_eq!(
+            vec!["Tea"],
+            trie.iter_prefix("Tea").collect::<Vec<String>>()
+        );
+        assert_eq!(0, trie.iter_prefix("X").count());
+        assert_eq!(0, Trie::new().iter().count());
+    }
+
+    #[test]
+    fn trie_source() {
+        let mut trie = Trie::new();
+        trie.insert_with_source("张三", 1);
+        trie.insert_with_source("李四", 2);
+        trie.insert_with_source("张三", 2);
+        trie.insert("王五");
+        assert_eq!(Some(1), trie.source("张三"));
+        assert_eq!(Some(2), trie.source("李四"));
+        assert_eq!(Some(0), trie.source("王五"));
+        assert_eq!(None, trie.source("张"));
+        assert_eq!(
+            HashMap::from([(0, 1), (1, 1), (2, 1)]),
+            trie.source_counts()
+        );
+    }
+
+    #[test]
+    fn trie_delete() {
+        let mut trie = Trie::new();
+        trie.insert("Test");
+        trie.insert("Tea");
+        trie.insert("申艳超");
+        assert_eq!(3, trie.size());
+        assert_eq!(8, node_count(&trie.root));
+
+        assert!(!trie.delete("Te"));
+        assert!(!trie.delete("Testing"));
+        assert!(!trie.delete("申超"));
+        assert_eq!(3, trie.size());
+
+        assert!(trie.delete("Test"));
+        assert!(!trie.delete("Test"));
+        assert_eq!(2, trie.size());
+        assert!(trie.exist("Tea"));
+        // only the shared "Te" branch is left of "Test"
+        assert_eq!(6, node_count(&trie.root));
+
+        assert!(trie.delete("申艳超"));
+        assert!(trie.delete("Tea"));
+        assert_eq!(0, trie.size());
+        assert_eq!(0, node_count(&trie.root));
+    }
+
+    fn assert_words(trie: &Trie, words: &[&str]) {
+        assert_eq!(words.len(), trie.size());
+        assert_eq!(words.to_vec(), trie.iter().collect::<Vec<String>>());
+        for word in words.iter() {
+            assert!(trie.exist(word), "{}", word);
+        }
+    }
+
+    #[test]
+    fn trie_insert_prefix_after_word() {
+        let mut trie = Trie::new();
+        assert!(trie.insert("Background"));
+        assert!(trie.insert("Back"));
+        assert!(trie.insert("B"));
+        assert!(!trie.exist("Ba"));
+        assert_words(&trie, &["B", "Back", "Background"]);
+    }
+
+    #[test]
+    fn trie_insert_superstring_after_word() {
+        let mut trie = Trie::new();
+        assert!(trie.insert("B"));
+        assert!(trie.insert("Back"));
+        assert!(trie.insert("Background"));
+        assert!(!trie.exist("Backg"));
+        assert_words(&trie, &["B", "Back", "Background"]);
+    }
+
+    #[test]
+    fn trie_insert_duplicate() {
+        let mut trie = Trie::new();
+        assert!(trie.insert_with_source("申艳超", 1));
+        assert!(!trie.insert_with_source("申艳超", 2));
+        assert!(trie.insert("申艳"));
+        assert!(!trie.insert("申艳"));
+        assert!(!trie.insert("申艳超"));
+        assert_eq!(Some(1), trie.source("申艳超"));
+        assert_words(&trie, &["申艳", "申艳超"]);
+    }
+
+    #[test]
+    fn trie_insert_after_delete() {
+        let mut trie = Trie::new();
+        trie.insert("Back");
+        trie.insert("Background");
+        assert!(trie.delete("Back"));
+        assert!(!trie.exist("Back"));
+        assert_words(&trie, &["Background"]);
+        assert!(trie.insert("Back"));
+        assert_words(&trie, &["Back", "Background"]);
+        assert!(!trie.insert(""));
+        assert!(!trie.exist(""));
+    }
+
+    proptest! {
+        // short words over a small alphabet, so that prefixes and duplicates are common
+        #[test]
+        fn trie_like_hash_set(
+            ops in prop::collection::vec((any::<bool>(), "[ab中]{1,4}"), 0..64)
+        ) {
+            let mut trie = Trie::new();
+            let mut model = HashSet::new();
+            for (insert, word) in ops.iter() {
+                if *insert {
+                    prop_assert_eq!(model.insert(word.clone()), trie.insert(word));
+                } else {
+                    prop_assert_eq!(model.remove(word), trie.delete(word));
+                }
+                prop_assert_eq!(model.len(), trie.size());
+            }
+            let mut words: Vec<String> = model.iter().cloned().collect();
+            words.sort();
+            prop_assert_eq!(words.clone(), trie.iter().collect::<Vec<String>>());
+            for (_, word) in ops.iter() {
+                prop_assert_eq!(model.contains(word), trie.exist(word));
+            }
+            for word in words.iter() {
+                prop_assert!(trie.delete(word));
+            }
+            prop_assert_eq!(0, trie.size());
+            prop_assert_eq!(0, node_count(&trie.root));
+        }
+    }
+
     #[test]
     fn test_thread_safe() {
         let trie = Trie::new();
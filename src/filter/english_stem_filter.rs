@@ -0,0 +1,63 @@
+use rust_stemmers::{Algorithm, Stemmer};
+
+use crate::core::lexeme::{Lexeme, LexemeType};
+use crate::filter::token_filter::TokenFilter;
+
+const FILTER_NAME: &str = "ENGLISH_STEM_FILTER";
+
+/// Snowball (Porter2) stemming of ENGLISH lexemes: running | runs -> run
+/// the original form stays at the same position when `keep_original` is on
+pub struct EnglishStemFilter {
+    stemmer: Stemmer,
+    keep_original: bool,
+}
+
+impl Default for EnglishStemFilter {
+    fn default() -> Self {
+        EnglishStemFilter {
+            stemmer: Stemmer::create(Algorithm::English),
+            keep_original: false,
+        }
+    }
+}
+
+impl EnglishStemFilter {
+    pub fn new() -> Self {
+        EnglishStemFilter::default()
+    }
+
+    /// emit the original word besides a different stem, default off
+    pub fn keep_original(mut self, keep_original: bool) -> Self {
+        self.keep_original = keep_original;
+        self
+    }
+}
+
+impl TokenFilter for EnglishStemFilter {
+    fn filter(&self, lexemes: Vec<Lexeme>) -> Vec<Lexeme> {
+        let mut results = Vec::with_capacity(lexemes.len());
+        for lexeme in lexemes {
+            if *lexeme.lexeme_type() != LexemeType::ENGLISH {
+                results.push(lexeme);
+                continue;
+            }
+            let stem = self.stemmer.stem(lexeme.lexeme_text()).into_owned();
+            if stem == lexeme.lexeme_text() {
+                results.push(lexeme);
+                continue;
+            }
+            let mut stemmed = lexeme.clone();
+            stemmed.set_lexeme_text(stem);
+            if self.keep_original {
+                stemmed.set_position_increment(0);
+                results.push(lexeme);
+            }
+            results.push(stemmed);
+        }
+        results
+    }
+
+    fn name(&self) -> &str {
+        FILTER_NAME
+    }
+}
@@ -0,0 +1,31 @@
+use std::ops::RangeInclusive;
+
+use crate::core::char_util::utf8_len;
+use crate::core::lexeme::Lexeme;
+use crate::filter::token_filter::{retain_lexemes, TokenFilter};
+
+const FILTER_NAME: &str = "LENGTH_FILTER";
+
+/// keep lexemes whose text has `min..=max` chars, dropped ones are left as position gaps
+#[derive(Debug, Clone)]
+pub struct LengthFilter {
+    length: RangeInclusive<usize>,
+}
+
+impl LengthFilter {
+    pub fn new(min: usize, max: usize) -> Self {
+        LengthFilter { length: min..=max }
+    }
+}
+
+impl TokenFilter for LengthFilter {
+    fn filter(&self, lexemes: Vec<Lexeme>) -> Vec<Lexeme> {
+        retain_lexemes(lexemes, |lexeme| {
+            self.length.contains(&utf8_len(lexeme.lexeme_text()))
+        })
+    }
+
+    fn name(&self) -> &str {
+        FILTER_NAME
+    }
+}
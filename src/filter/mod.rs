@@ -0,0 +1,7 @@
+pub mod english_stem_filter;
+pub mod length_filter;
+pub mod pinyin_filter;
+pub mod stop_word_filter;
+pub mod synonym_filter;
+pub mod token_filter;
+pub mod type_filter;
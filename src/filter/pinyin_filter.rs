@@ -0,0 +1,175 @@
+use std::collections::HashMap;
+
+use once_cell::sync::Lazy;
+use pinyin::{ToPinyin, ToPinyinMulti};
+
+use crate::core::lexeme::{Lexeme, LexemeType};
+use crate::filter::token_filter::TokenFilter;
+
+const FILTER_NAME: &str = "PINYIN_FILTER";
+
+// readings of polyphones decided by the word around them: 银行 -> yin hang, 重庆 -> chong qing
+const POLYPHONE_WORDS: &str = include_str!("../../dict/pinyin/polyphone.dic");
+
+static POLYPHONE_MAP: Lazy<HashMap<Vec<char>, Vec<String>>> = Lazy::new(|| {
+    POLYPHONE_WORDS
+        .lines()
+        .filter_map(|line| {
+            let mut parts = line.split_whitespace();
+            let word: Vec<char> = parts.next()?.chars().collect();
+            let syllables: Vec<String> = parts.map(String::from).collect();
+            (word.len() == syllables.len()).then_some((word, syllables))
+        })
+        .collect()
+});
+
+static MAX_POLYPHONE_LEN: Lazy<usize> =
+    Lazy::new(|| POLYPHONE_MAP.keys().map(|k| k.len()).max().unwrap_or(0));
+
+/// add PINYIN lexemes at the same offsets of CN_WORD/CN_CHAR lexemes
+/// 中华 -> 中华 | zhonghua | zh, 银行 -> 银行 | yinhang | yh
+/// a single CN_CHAR gets all of its readings: 行 -> 行 | xing | x | hang | h | heng
+#[derive(Debug, Clone)]
+pub struct PinyinFilter {
+    full_pinyin: bool,
+    first_letter: bool,
+    keep_original: bool,
+}
+
+impl Default for PinyinFilter {
+    fn default() -> Self {
+        PinyinFilter {
+            full_pinyin: true,
+            first_letter: true,
+            keep_original: true,
+        }
+    }
+}
+
+impl PinyinFilter {
+    pub fn new() -> Self {
+        PinyinFilter::default()
+    }
+
+    /// emit the whole pinyin of a word: zhonghua, default on
+    pub fn full_pinyin(mut self, full_pinyin: bool) -> Self {
+        self.full_pinyin = full_pinyin;
+        self
+    }
+
+    /// emit the first letters of a word: zh, default on
+    pub fn first_letter(mut self, first_letter: bool) -> Self {
+        self.first_letter = first_letter;
+        self
+    }
+
+    /// keep the chinese lexeme itself, default on
+    pub fn keep_original(mut self, keep_original: bool) -> Self {
+        self.keep_original = keep_original;
+        self
+    }
+}
+
+impl TokenFilter for PinyinFilter {
+    fn filter(&self, lexemes: Vec<Lexeme>) -> Vec<Lexeme> {
+        let mut results = Vec::with_capacity(lexemes.len() * 3);
+        for lexeme in lexemes {
+            let readings = match lexeme.lexeme_type() {
+                LexemeType::CNWORD | LexemeType::CNCHAR => to_readings(lexeme.lexeme_text()),
+                _ => Vec::new(),
+            };
+            if readings.is_empty() || self.keep_original {
+                results.push(lexeme.clone());
+            }
+            let mut texts: Vec<String> = Vec::new();
+            for syllables in readings.iter() {
+                if self.full_pinyin {
+                    texts.push(syllables.concat());
+                }
+                if self.first_letter {
+                    texts.push(syllables.iter().filter_map(|s| s.chars().next()).collect());
+                }
+            }
+            let mut emitted: Vec<&str> = Vec::new();
+            for text in texts.iter() {
+                if emitted.contains(&text.as_str()) {
+                    continue;
+                }
+                emitted.push(text);
+                let mut pinyin = lexeme.clone();
+                pinyin.set_lexeme_type(LexemeType::PINYIN);
+                pinyin.set_lexeme_text(text.clone());
+                if self.keep_original || emitted.len() > 1 {
+                    pinyin.set_position_increment(0);
+                }
+                results.push(pinyin);
+            }
+        }
+        results
+    }
+
+    fn name(&self) -> &str {
+        FILTER_NAME
+    }
+}
+
+/// pinyin syllables of a word, words in the polyphone dictionary take their readings
+/// from there, other chars the most common one. A single char gets all of its readings
+pub fn to_readings(text: &str) -> Vec<Vec<String>> {
+    let chars: Vec<char> = text.chars().collect();
+    if chars.len() == 1 {
+        let mut readings: Vec<Vec<String>> = Vec::new();
+        if let Some(multi) = chars[0].to_pinyin_multi() {
+            for pinyin in multi {
+                let syllable = normalize(pinyin.plain());
+                if !readings.iter().any(|r| r[0] == syllable) {
+                    readings.push(vec![syllable]);
+                }
+            }
+        }
+        return readings;
+    }
+    let mut syllables = Vec::with_capacity(chars.len());
+    let mut cursor = 0;
+    'outer: while cursor < chars.len() {
+        let max_len = (*MAX_POLYPHONE_LEN).min(chars.len() - cursor);
+        for len in (2..=max_len).rev() {
+            if let Some(word) = POLYPHONE_MAP.get(&chars[cursor..cursor + len]) {
+                syllables.extend(word.iter().cloned());
+                cursor += len;
+                continue 'outer;
+            }
+        }
+        match chars[cursor].to_pinyin() {
+            Some(pinyin) => syllables.push(normalize(pinyin.plain())),
+            None => return Vec::new(),
+        }
+        cursor += 1;
+    }
+    vec![syllables]
+}
+
+// ü is typed as v: lü -> lv
+fn normalize(syllable: &str) -> String {
+    syllable.replace('ü', "v")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn readings(text: &str) -> Vec<String> {
+        to_readings(text).iter().map(|r| r.join(" ")).collect()
+    }
+
+    #[test]
+    fn test_to_readings() {
+        assert_eq!(vec!["zhong hua"], readings("中华"));
+        assert_eq!(vec!["yin hang"], readings("银行"));
+        assert_eq!(vec!["yin hang hang zhang"], readings("银行行长"));
+        assert_eq!(vec!["chong qing shi"], readings("重庆市"));
+        assert_eq!(vec!["xiao lv"], readings("效率"));
+        assert_eq!(vec!["xing", "hang", "heng"], readings("行"));
+        assert!(readings("ab").is_empty());
+    }
+}
@@ -0,0 +1,81 @@
+use std::collections::HashSet;
+
+use crate::core::char_util::{regularize_str, utf8_len};
+use crate::core::lexeme::Lexeme;
+use crate::dict::dictionary::GLOBAL_DICT;
+use crate::filter::token_filter::{retain_lexemes, TokenFilter};
+
+const FILTER_NAME: &str = "STOP_WORD_FILTER";
+
+/// stop words applied to one call
+#[derive(Debug, Clone, Default)]
+pub enum StopWords {
+    /// keep every lexeme, for phrase queries like "to be or not"
+    Disabled,
+    /// `stop_word_dict` and `ext_stop_word_dicts` of ik.yml
+    #[default]
+    Default,
+    /// caller supplied set, compared with the regularized lexeme text
+    Custom(HashSet<String>),
+}
+
+impl StopWords {
+    /// custom stop words regularized the same way as the input text: "The" -> "the"
+    pub fn custom(words: &[&str]) -> Self {
+        StopWords::Custom(words.iter().map(|word| regularize_str(word)).collect())
+    }
+}
+
+/// drop stop word lexemes, the position increments of dropped lexemes are added to the next
+/// kept lexeme so that phrase queries still see the gaps
+#[derive(Debug, Default, Clone)]
+pub struct StopWordFilter {
+    stop_words: StopWords,
+}
+
+impl StopWordFilter {
+    pub fn new() -> Self {
+        StopWordFilter::default()
+    }
+
+    pub fn with_stop_words(stop_words: StopWords) -> Self {
+        StopWordFilter { stop_words }
+    }
+
+    /// filter with borrowed stop words, so a caller holding the set does not clone it per call
+    pub fn filter_with(stop_words: &StopWords, lexemes: Vec<Lexeme>) -> Vec<Lexeme> {
+        match stop_words {
+            StopWords::Disabled => lexemes,
+            StopWords::Default => {
+                let lock_guard = {
+                    cfg_if::cfg_if! {
+                        if #[cfg(feature="use-parking-lot")] {Some(GLOBAL_DICT.read())}
+                        else /*if #[cfg(feature="use-std-sync")]*/ {GLOBAL_DICT.read().ok()}
+                    }
+                };
+                match lock_guard {
+                    Some(dict) => remove_stop_words(lexemes, |text| {
+                        dict.is_stop_word(text, 0, utf8_len(text))
+                    }),
+                    None => lexemes,
+                }
+            }
+            StopWords::Custom(words) => remove_stop_words(lexemes, |text| words.contains(text)),
+        }
+    }
+}
+
+impl TokenFilter for StopWordFilter {
+    fn filter(&self, lexemes: Vec<Lexeme>) -> Vec<Lexeme> {
+        Self::filter_with(&self.stop_words, lexemes)
+    }
+
+    fn name(&self) -> &str {
+        FILTER_NAME
+    }
+}
+
+fn remove_stop_words<F>(lexemes: Vec<Lexeme>, is_stop_word: F) -> Vec<Lexeme>
+where F: Fn(&str) -> bool {
+    retain_lexemes(lexemes, |lexeme| !is_stop_word(lexeme.lexeme_text()))
+}
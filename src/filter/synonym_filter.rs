@@ -0,0 +1,96 @@
+use crate::core::lexeme::{Lexeme, LexemeType};
+use crate::dict::dictionary::{Dictionary, GLOBAL_DICT};
+use crate::filter::token_filter::TokenFilter;
+
+const FILTER_NAME: &str = "SYNONYM_FILTER";
+
+/// inject SYNONYM lexemes with zero position increment, synonyms come from `synonym_dicts` in
+/// ik.yml 土豆 -> 土豆 | 马铃薯 | 洋芋
+/// a synonym may span several adjacent lexemes: 番茄 | 炒蛋 -> 番茄 | 西红柿炒鸡蛋 | 炒蛋
+/// a synonym of several words, such as "new york", is injected as one lexeme with the space
+/// in its text, it is not split into positioned lexemes
+#[derive(Debug, Default, Clone)]
+pub struct SynonymFilter {}
+
+impl SynonymFilter {
+    pub fn new() -> Self {
+        SynonymFilter::default()
+    }
+}
+
+impl TokenFilter for SynonymFilter {
+    fn filter(&self, lexemes: Vec<Lexeme>) -> Vec<Lexeme> {
+        let lock_guard = {
+            cfg_if::cfg_if! {
+                if #[cfg(feature="use-parking-lot")] {Some(GLOBAL_DICT.read())}
+                else /*if #[cfg(feature="use-std-sync")]*/ {GLOBAL_DICT.read().ok()}
+            }
+        };
+        match lock_guard {
+            Some(dict) => inject_synonyms(&dict, lexemes),
+            None => lexemes,
+        }
+    }
+
+    fn name(&self) -> &str {
+        FILTER_NAME
+    }
+}
+
+fn inject_synonyms(dict: &Dictionary, lexemes: Vec<Lexeme>) -> Vec<Lexeme> {
+    let mut results = Vec::with_capacity(lexemes.len());
+    let mut positions = Vec::with_capacity(lexemes.len());
+    let mut position = 0;
+    for lexeme in lexemes.iter() {
+        position += lexeme.position_increment();
+        positions.push(position);
+    }
+    for (index, lexeme) in lexemes.iter().enumerate() {
+        results.push(lexeme.clone());
+        for (end_pos, position_length, synonyms) in
+            match_synonyms(dict, &lexemes, &positions, index)
+        {
+            for synonym in synonyms.iter() {
+                let mut new_lexeme = Lexeme::new(lexeme.begin_pos()..end_pos, LexemeType::SYNONYM);
+                new_lexeme.set_lexeme_text(synonym.clone());
+                new_lexeme.set_position_increment(0);
+                new_lexeme.set_position_length(position_length);
+                results.push(new_lexeme);
+            }
+        }
+    }
+    results
+}
+
+// synonyms of chains of adjacent lexemes starting at `index`, longest chain first,
+// with the end offset and the positions spanned by the chain
+fn match_synonyms<'a>(
+    dict: &'a Dictionary,
+    lexemes: &[Lexeme],
+    positions: &[usize],
+    index: usize,
+) -> Vec<(usize, usize, &'a Vec<String>)> {
+    let mut matches = Vec::new();
+    let mut text = String::new();
+    let mut current = index;
+    loop {
+        let lexeme = &lexemes[current];
+        text.push_str(lexeme.lexeme_text());
+        if text.chars().count() > dict.max_synonym_len() {
+            break;
+        }
+        if let Some(synonyms) = dict.synonyms(&text) {
+            // from the first position to the end of the last lexeme, overlapping INDEX mode
+            // lexemes would be counted twice by summing the lengths
+            let position_length = positions[current] - positions[index] + lexeme.position_length();
+            matches.push((lexeme.end_pos(), position_length, synonyms));
+        }
+        // next lexeme starting where this one ends, INDEX mode lexemes may overlap
+        match (current + 1..lexemes.len()).find(|i| lexemes[*i].begin_pos() == lexeme.end_pos()) {
+            Some(next) => current = next,
+            None => break,
+        }
+    }
+    matches.reverse();
+    matches
+}
@@ -0,0 +1,27 @@
+use crate::core::lexeme::Lexeme;
+
+/// one stage of an analysis chain, runs over the whole output of the previous stage
+pub trait TokenFilter: Send + Sync {
+    fn filter(&self, lexemes: Vec<Lexeme>) -> Vec<Lexeme>;
+    fn name(&self) -> &str;
+}
+
+/// keep the lexemes `keep` accepts, the position increments of dropped lexemes are added to the
+/// next kept lexeme so that positions after a gap stay and no stacked lexeme becomes the first
+pub fn retain_lexemes<F>(lexemes: Vec<Lexeme>, keep: F) -> Vec<Lexeme>
+where F: Fn(&Lexeme) -> bool {
+    let mut results = Vec::with_capacity(lexemes.len());
+    let mut skipped_increment = 0;
+    for mut lexeme in lexemes {
+        if !keep(&lexeme) {
+            skipped_increment += lexeme.position_increment();
+            continue;
+        }
+        if skipped_increment > 0 {
+            lexeme.set_position_increment(lexeme.position_increment() + skipped_increment);
+            skipped_increment = 0;
+        }
+        results.push(lexeme);
+    }
+    results
+}
@@ -0,0 +1,40 @@
+use crate::core::lexeme::{Lexeme, LexemeType};
+use crate::filter::token_filter::{retain_lexemes, TokenFilter};
+
+const FILTER_NAME: &str = "TYPE_FILTER";
+
+/// keep only the listed lexeme types, or drop them when built by `TypeFilter::exclude`,
+/// dropped ones are left as position gaps
+#[derive(Debug, Clone)]
+pub struct TypeFilter {
+    types: Vec<LexemeType>,
+    include: bool,
+}
+
+impl TypeFilter {
+    pub fn include(types: &[LexemeType]) -> Self {
+        TypeFilter {
+            types: types.to_vec(),
+            include: true,
+        }
+    }
+
+    pub fn exclude(types: &[LexemeType]) -> Self {
+        TypeFilter {
+            types: types.to_vec(),
+            include: false,
+        }
+    }
+}
+
+impl TokenFilter for TypeFilter {
+    fn filter(&self, lexemes: Vec<Lexeme>) -> Vec<Lexeme> {
+        retain_lexemes(lexemes, |lexeme| {
+            self.types.contains(lexeme.lexeme_type()) == self.include
+        })
+    }
+
+    fn name(&self) -> &str {
+        FILTER_NAME
+    }
+}
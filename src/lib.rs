@@ -1,3 +1,4 @@
 pub(crate) mod config;
 pub mod core;
 pub mod dict;
+pub mod filter;
@@ -1,6 +1,15 @@
 #[cfg(test)]
 mod test {
+    use ik_rs::core::analyzer::Analyzer;
     use ik_rs::core::ik_segmenter::{IKSegmenter, TokenMode};
+    use ik_rs::core::lexeme::{Lexeme, LexemeType};
+    use ik_rs::filter::english_stem_filter::EnglishStemFilter;
+    use ik_rs::filter::length_filter::LengthFilter;
+    use ik_rs::filter::pinyin_filter::PinyinFilter;
+    use ik_rs::filter::stop_word_filter::{StopWordFilter, StopWords};
+    use ik_rs::filter::synonym_filter::SynonymFilter;
+    use ik_rs::filter::token_filter::TokenFilter;
+    use ik_rs::filter::type_filter::TypeFilter;
     use once_cell::sync::Lazy;
 
     cfg_if::cfg_if! {
@@ -86,6 +95,541 @@ mod test {
             vec!["中国", "有", "960万平方公里", "的", "国土"],
         );
     }
+    #[test]
+    fn test_other_cjk_segment() {
+        assert_index_token("こんにちは", vec!["こん", "んに", "にち", "ちは"]);
+        assert_search_token("こんにちは", vec!["こん", "んに", "にち", "ちは"]);
+        assert_search_token("中国へ", vec!["中国", "へ"]);
+        let ik = IKSegmenter::new();
+        let positions: Vec<(usize, usize)> = ik
+            .tokenize("こんにちは", TokenMode::SEARCH)
+            .iter()
+            .map(|t| (t.position_increment(), t.position_length()))
+            .collect();
+        assert_eq!(vec![(1, 1), (1, 1), (1, 1), (1, 1)], positions);
+        assert_index_token("안녕하세요", vec!["안녕", "녕하", "하세", "세요"]);
+
+        let ik = IKSegmenter::builder().group_cjk_runs(true).build();
+        let tokens = ik.tokenize("コンピュータを使う", TokenMode::INDEX);
+        let token_texts: Vec<&str> = tokens.iter().map(|t| t.lexeme_text()).collect();
+        assert_eq!(vec!["コンピュータ", "を", "使", "う"], token_texts);
+        let tokens = ik.tokenize("안녕하세요 세계", TokenMode::SEARCH);
+        let token_texts: Vec<&str> = tokens.iter().map(|t| t.lexeme_text()).collect();
+        assert_eq!(vec!["안녕하세요", "세계"], token_texts);
+    }
+
+    #[test]
+    fn test_numeric_value() {
+        let lock_guard = {
+            cfg_if::cfg_if! {
+                if #[cfg(feature="use-parking-lot")] {GLOBAL_IK.read()}
+                else /*#cfg(feature="use-std-sync")*/ {GLOBAL_IK.read().unwrap()}
+            }
+        };
+        for text in ["九百六十万", "960万"] {
+            let tokens = lock_guard.tokenize(text, TokenMode::SEARCH);
+            assert_eq!(1, tokens.len());
+            assert_eq!(Some(9600000.0), tokens[0].numeric_value());
+        }
+        let tokens = lock_guard.tokenize("中国有960万平方公里的国土", TokenMode::SEARCH);
+        assert_eq!("960万平方公里", tokens[2].lexeme_text());
+        assert_eq!(Some(9600000.0), tokens[2].numeric_value());
+        let tokens = lock_guard.tokenize("壹佰贰拾元", TokenMode::SEARCH);
+        assert_eq!(Some(120.0), tokens[0].numeric_value());
+    }
+
+    #[test]
+    fn test_numeric_form_segment() {
+        assert_search_token("百分之三十的人", vec!["百分之三十", "的人"]);
+        assert_search_token("三分之一", vec!["三分之一"]);
+        assert_search_token("增长3.5%", vec!["增长", "3.5%"]);
+        assert_search_token("三点五公斤", vec!["三点五公斤"]);
+
+        let lock_guard = {
+            cfg_if::cfg_if! {
+                if #[cfg(feature="use-parking-lot")] {GLOBAL_IK.read()}
+                else /*#cfg(feature="use-std-sync")*/ {GLOBAL_IK.read().unwrap()}
+            }
+        };
+        let tokens = lock_guard.tokenize("百分之三十", TokenMode::SEARCH);
+        assert_eq!("TYPE_PERCENT", tokens[0].lexeme_type().as_str());
+        assert_eq!(Some(0.3), tokens[0].numeric_value());
+        let tokens = lock_guard.tokenize("3.5%", TokenMode::SEARCH);
+        assert_eq!(Some(0.035), tokens[0].numeric_value());
+        let tokens = lock_guard.tokenize("四分之一", TokenMode::SEARCH);
+        assert_eq!("TYPE_FRACTION", tokens[0].lexeme_type().as_str());
+        assert_eq!(Some(0.25), tokens[0].numeric_value());
+        let tokens = lock_guard.tokenize("三点五公斤", TokenMode::SEARCH);
+        assert_eq!("TYPE_CQUAN", tokens[0].lexeme_type().as_str());
+        assert_eq!(Some(3.5), tokens[0].numeric_value());
+    }
+
+    #[test]
+    fn test_datetime_segment() {
+        assert_search_token("2024年10月18日发布", vec!["2024年10月18日", "发布"]);
+        assert_search_token("二〇二四年", vec!["二〇二四年"]);
+        assert_search_token("下午三点半开会", vec!["下午三点半", "开会"]);
+        assert_search_token("发布于2024-10-18", vec!["发布", "于", "2024-10-18"]);
+
+        let lock_guard = {
+            cfg_if::cfg_if! {
+                if #[cfg(feature="use-parking-lot")] {GLOBAL_IK.read()}
+                else /*#cfg(feature="use-std-sync")*/ {GLOBAL_IK.read().unwrap()}
+            }
+        };
+        let tokens = lock_guard.tokenize("2024年10月18日下午三点半", TokenMode::SEARCH);
+        assert_eq!(1, tokens.len());
+        assert_eq!("DATETIME", tokens[0].lexeme_type().as_str());
+        assert_eq!(Some("2024-10-18T15:30"), tokens[0].datetime_value());
+        let tokens = lock_guard.tokenize("2024-10-18", TokenMode::INDEX);
+        assert_eq!("DATETIME", tokens[0].lexeme_type().as_str());
+        assert_eq!(Some("2024-10-18"), tokens[0].datetime_value());
+        for text in ["地铁三号线", "10号楼", "三号", "10月"] {
+            let tokens = lock_guard.tokenize(text, TokenMode::INDEX);
+            assert!(
+                tokens
+                    .iter()
+                    .all(|t| t.lexeme_type().as_str() != "DATETIME"),
+                "{:?}",
+                tokens
+            );
+        }
+    }
+
+    #[test]
+    fn test_entity_segment() {
+        assert_search_token(
+            "访问https://github.com/blueshen/ik-rs?tab=1看看",
+            vec!["访问", "https://github.com/blueshen/ik-rs?tab=1", "看看"],
+        );
+        assert_search_token(
+            "服务器192.168.1.1宕机",
+            vec!["服务器", "192.168.1.1", "宕机"],
+        );
+        assert_search_token("地址是2001:db8::1", vec!["地址", "是", "2001:db8::1"]);
+        assert_search_token("电话13812345678", vec!["电话", "13812345678"]);
+        assert_index_token(
+            "http://www.example.com:8080/a~b/c",
+            vec![
+                "http://www.example.com:8080/a~b/c",
+                "http",
+                "www.example.com",
+                "www",
+                "example",
+                "com",
+                "8080",
+                "a~b",
+                "a",
+                "b",
+                "c",
+            ],
+        );
+
+        let ik = IKSegmenter::builder().entity_sub_tokens(false).build();
+        let tokens = ik.tokenize("http://www.example.com:8080/a~b/c", TokenMode::INDEX);
+        let token_texts: Vec<&str> = tokens.iter().map(|t| t.lexeme_text()).collect();
+        assert_eq!(vec!["http://www.example.com:8080/a~b/c"], token_texts);
+        let tokens = ik.tokenize("zhiyi.shen@gmail.com", TokenMode::INDEX);
+        let token_texts: Vec<&str> = tokens.iter().map(|t| t.lexeme_text()).collect();
+        assert_eq!(vec!["zhiyi.shen@gmail.com"], token_texts);
+
+        // entities after a label or in brackets
+        assert_search_token("电话：13812345678", vec!["电话", "13812345678"]);
+        assert_search_token("邮箱:a.b@gmail.com", vec!["邮箱", "a.b@gmail.com"]);
+        assert_search_token(
+            "网址:https://github.com/a",
+            vec!["网址", "https://github.com/a"],
+        );
+        assert_search_token("IP=192.168.1.1", vec!["ip", "192.168.1.1"]);
+        assert_search_token("（192.168.1.1）", vec!["192.168.1.1"]);
+        assert_search_token("(13812345678)", vec!["13812345678"]);
+        // still not inside a word
+        assert_search_token("abc13812345678", vec!["abc13812345678"]);
+        let tokens = ik.tokenize(
+            "zhiyi.shen@gmail.com 192.168.1.1 138-1234-5678",
+            TokenMode::SEARCH,
+        );
+        let token_types: Vec<&str> = tokens.iter().map(|t| t.lexeme_type().as_str()).collect();
+        assert_eq!(vec!["EMAIL", "IP", "PHONE"], token_types);
+    }
+
+    #[test]
+    fn test_symbol_segment() {
+        // dropped by default
+        assert_search_token("笑死😂", vec!["笑死"]);
+
+        let ik = IKSegmenter::builder().emit_symbols(true).build();
+        let tokens = ik.tokenize("笑死😂👍🏽 一家👨‍👩‍👧 🇨🇳 ¥100≈$14", TokenMode::SEARCH);
+        let token_texts: Vec<&str> = tokens.iter().map(|t| t.lexeme_text()).collect();
+        assert_eq!(
+            vec![
+                "笑死",
+                "😂",
+                "👍🏽",
+                "一家",
+                "👨‍👩‍👧",
+                "🇨🇳",
+                "¥",
+                "100",
+                "≈",
+                "$",
+                "14"
+            ],
+            token_texts
+        );
+        let token_types: Vec<&str> = tokens.iter().map(|t| t.lexeme_type().as_str()).collect();
+        assert_eq!("EMOJI", token_types[1]);
+        assert_eq!("SYMBOL", token_types[6]);
+    }
+
+    #[test]
+    fn test_case_change_segment() {
+        // not split by default
+        assert_index_token("getUserName", vec!["getusername"]);
+
+        let ik = IKSegmenter::builder().split_on_case_change(true).build();
+        let index_texts = |text: &str| -> Vec<String> {
+            ik.tokenize(text, TokenMode::INDEX)
+                .iter()
+                .map(|t| t.lexeme_text().to_string())
+                .collect()
+        };
+        assert_eq!(
+            vec!["getusername", "get", "user", "name"],
+            index_texts("getUserName")
+        );
+        assert_eq!(vec!["xmlhttp", "xml", "http"], index_texts("XMLHttp"));
+        assert_eq!(
+            vec![
+                "iphone15promax",
+                "iphone",
+                "i",
+                "phone",
+                "15",
+                "promax",
+                "pro",
+                "max"
+            ],
+            index_texts("iPhone15ProMax")
+        );
+        assert_eq!(
+            vec!["get_user_name", "get", "user", "name"],
+            index_texts("get_user_name")
+        );
+        let tokens = ik.tokenize("调用getUserName方法", TokenMode::SEARCH);
+        let token_texts: Vec<&str> = tokens.iter().map(|t| t.lexeme_text()).collect();
+        assert_eq!(vec!["调用", "getusername", "方法"], token_texts);
+    }
+
+    #[test]
+    fn test_connectors() {
+        let ik = IKSegmenter::builder()
+            .letter_connectors(&['/', ':', '.', '_'])
+            .num_connectors(&[])
+            .build();
+        let tokens = ik.tokenize("src/core/mod.rs ab-cd 1,000", TokenMode::INDEX);
+        let token_texts: Vec<&str> = tokens.iter().map(|t| t.lexeme_text()).collect();
+        assert_eq!(
+            vec![
+                "src/core/mod.rs",
+                "src",
+                "core",
+                "mod",
+                "rs",
+                "ab",
+                "cd",
+                "1",
+                "000"
+            ],
+            token_texts
+        );
+
+        // connectors classed as SYMBOL
+        let ik = IKSegmenter::builder()
+            .letter_connectors(&['→'])
+            .num_connectors(&['$'])
+            .build();
+        let tokens = ik.tokenize("a→b 1$000", TokenMode::SEARCH);
+        let token_texts: Vec<&str> = tokens.iter().map(|t| t.lexeme_text()).collect();
+        assert_eq!(vec!["a→b", "1$000"], token_texts);
+        assert_search_token("c++ a+b", vec!["c++", "a+b"]);
+    }
+
+    #[test]
+    fn test_traditional_to_simplified() {
+        let ik = IKSegmenter::builder()
+            .traditional_to_simplified(true)
+            .build();
+        let text = "中華人民共和國的電腦乾乾淨淨";
+        let tokens = ik.tokenize(text, TokenMode::SEARCH);
+        let token_texts: Vec<&str> = tokens.iter().map(|t| t.lexeme_text()).collect();
+        assert_eq!(
+            vec!["中华人民共和国", "的", "电脑", "干干净净"],
+            token_texts
+        );
+        let origin_texts: Vec<String> = tokens
+            .iter()
+            .map(|t| text.chars().skip(t.begin_pos()).take(t.len()).collect())
+            .collect();
+        assert_eq!(
+            vec!["中華人民共和國", "的", "電腦", "乾乾淨淨"],
+            origin_texts
+        );
+    }
+
+    #[test]
+    fn test_pinyin_filter() {
+        let lock_guard = {
+            cfg_if::cfg_if! {
+                if #[cfg(feature="use-parking-lot")] {GLOBAL_IK.read()}
+                else /*#cfg(feature="use-std-sync")*/ {GLOBAL_IK.read().unwrap()}
+            }
+        };
+        let tokens = lock_guard.tokenize("中华银行", TokenMode::SEARCH);
+        let tokens = PinyinFilter::new().filter(tokens);
+        let token_texts: Vec<(&str, usize)> = tokens
+            .iter()
+            .map(|t| (t.lexeme_text(), t.begin_pos()))
+            .collect();
+        assert_eq!(
+            vec![
+                ("中华", 0),
+                ("zhonghua", 0),
+                ("zh", 0),
+                ("银行", 2),
+                ("yinhang", 2),
+                ("yh", 2)
+            ],
+            token_texts
+        );
+        let tokens = lock_guard.tokenize("重庆", TokenMode::SEARCH);
+        let tokens = PinyinFilter::new()
+            .first_letter(false)
+            .keep_original(false)
+            .filter(tokens);
+        let token_texts: Vec<&str> = tokens.iter().map(|t| t.lexeme_text()).collect();
+        assert_eq!(vec!["chongqing"], token_texts);
+    }
+
+    #[test]
+    fn test_synonym_filter() {
+        let lock_guard = {
+            cfg_if::cfg_if! {
+                if #[cfg(feature="use-parking-lot")] {GLOBAL_IK.read()}
+                else /*#cfg(feature="use-std-sync")*/ {GLOBAL_IK.read().unwrap()}
+            }
+        };
+        let tokens = lock_guard.tokenize("番茄炒蛋和土豆", TokenMode::SEARCH);
+        let tokens = SynonymFilter::new().filter(tokens);
+        let token_texts: Vec<(&str, usize, usize, usize)> = tokens
+            .iter()
+            .map(|t| {
+                (
+                    t.lexeme_text(),
+                    t.begin_pos(),
+                    t.position_increment(),
+                    t.position_length(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            vec![
+                ("番茄", 0, 1, 1),
+                ("西红柿炒鸡蛋", 0, 0, 2),
+                ("西红柿", 0, 0, 1),
+                ("炒蛋", 2, 1, 1),
+                ("和", 4, 1, 1),
+                ("土豆", 5, 1, 1),
+                ("马铃薯", 5, 0, 1),
+                ("洋芋", 5, 0, 1)
+            ],
+            token_texts
+        );
+
+        // INDEX mode: 番茄(0, length 2) | 茄炒(1) | 炒蛋(2, length 2) | 蛋(3)
+        let lexeme = |range: std::ops::Range<usize>, text: &str, position_length: usize| {
+            let mut lexeme = Lexeme::new(range, LexemeType::CNWORD);
+            lexeme.set_lexeme_text(text.to_string());
+            lexeme.set_position_increment(1);
+            lexeme.set_position_length(position_length);
+            lexeme
+        };
+        let tokens = SynonymFilter::new().filter(vec![
+            lexeme(0..2, "番茄", 2),
+            lexeme(1..3, "茄炒", 2),
+            lexeme(2..4, "炒蛋", 2),
+            lexeme(3..4, "蛋", 1),
+        ]);
+        let synonym = tokens
+            .iter()
+            .find(|t| t.lexeme_text() == "西红柿炒鸡蛋")
+            .unwrap();
+        assert_eq!(
+            (0, 4, 0, 4),
+            (
+                synonym.begin_pos(),
+                synonym.end_pos(),
+                synonym.position_increment(),
+                synonym.position_length()
+            )
+        );
+    }
+
+    #[test]
+    fn test_analyzer() {
+        let text = "我是中国人,土豆的拼音";
+        let analyzer = Analyzer::default();
+        let tokens = analyzer.analyze(text, TokenMode::SEARCH);
+        let expected = IKSegmenter::new().tokenize(text, TokenMode::SEARCH);
+        let token_texts: Vec<&str> = tokens.iter().map(|t| t.lexeme_text()).collect();
+        let expected_texts: Vec<&str> = expected.iter().map(|t| t.lexeme_text()).collect();
+        assert_eq!(expected_texts, token_texts);
+
+        let analyzer = Analyzer::new(IKSegmenter::new())
+            .filter(StopWordFilter::new())
+            .filter(TypeFilter::include(&[LexemeType::CNWORD]))
+            .filter(LengthFilter::new(2, 2))
+            .filter(SynonymFilter::new())
+            .filter(PinyinFilter::new().full_pinyin(false));
+        assert_eq!(
+            vec![
+                "STOP_WORD_FILTER",
+                "TYPE_FILTER",
+                "LENGTH_FILTER",
+                "SYNONYM_FILTER",
+                "PINYIN_FILTER"
+            ],
+            analyzer.filters()
+        );
+        let tokens = analyzer.analyze(text, TokenMode::SEARCH);
+        let token_texts: Vec<&str> = tokens.iter().map(|t| t.lexeme_text()).collect();
+        assert_eq!(
+            vec!["土豆", "td", "马铃薯", "洋芋", "拼音", "py"],
+            token_texts
+        );
+    }
+
+    #[test]
+    fn test_filter_position_gap() {
+        // 土豆(1) | 马铃薯(0) | 的(1) | 做法(1)
+        let lexeme = |range: std::ops::Range<usize>, text: &str, lexeme_type, increment| {
+            let mut lexeme = Lexeme::new(range, lexeme_type);
+            lexeme.set_lexeme_text(text.to_string());
+            lexeme.set_position_increment(increment);
+            lexeme
+        };
+        let lexemes = vec![
+            lexeme(0..2, "土豆", LexemeType::CNWORD, 1),
+            lexeme(0..2, "马铃薯", LexemeType::SYNONYM, 0),
+            lexeme(2..3, "的", LexemeType::CNCHAR, 1),
+            lexeme(3..5, "做法", LexemeType::CNWORD, 1),
+        ];
+        let increments = |lexemes: Vec<Lexeme>| -> Vec<(String, usize)> {
+            lexemes
+                .into_iter()
+                .map(|t| (t.lexeme_text().to_string(), t.position_increment()))
+                .collect()
+        };
+        let tokens = TypeFilter::exclude(&[LexemeType::CNWORD]).filter(lexemes.clone());
+        assert_eq!(
+            vec![("马铃薯".to_string(), 1), ("的".to_string(), 1)],
+            increments(tokens)
+        );
+        let tokens = LengthFilter::new(2, 2).filter(lexemes);
+        assert_eq!(
+            vec![("土豆".to_string(), 1), ("做法".to_string(), 2)],
+            increments(tokens)
+        );
+    }
+
+    #[test]
+    fn test_english_stem_filter() {
+        let lock_guard = {
+            cfg_if::cfg_if! {
+                if #[cfg(feature="use-parking-lot")] {GLOBAL_IK.read()}
+                else /*#cfg(feature="use-std-sync")*/ {GLOBAL_IK.read().unwrap()}
+            }
+        };
+        let tokens = lock_guard.tokenize("running和runs都是跑", TokenMode::SEARCH);
+        let stemmed = EnglishStemFilter::new().filter(tokens.clone());
+        let token_texts: Vec<&str> = stemmed.iter().map(|t| t.lexeme_text()).collect();
+        assert_eq!(vec!["run", "和", "run", "都是", "跑"], token_texts);
+
+        let stemmed = EnglishStemFilter::new().keep_original(true).filter(tokens);
+        let token_texts: Vec<(&str, usize)> = stemmed
+            .iter()
+            .map(|t| (t.lexeme_text(), t.position_increment()))
+            .collect();
+        assert_eq!(
+            vec![
+                ("running", 1),
+                ("run", 0),
+                ("和", 1),
+                ("runs", 1),
+                ("run", 0),
+                ("都是", 1),
+                ("跑", 1)
+            ],
+            token_texts
+        );
+    }
+
+    #[test]
+    fn test_stop_words() {
+        let lock_guard = {
+            cfg_if::cfg_if! {
+                if #[cfg(feature="use-parking-lot")] {GLOBAL_IK.read()}
+                else /*#cfg(feature="use-std-sync")*/ {GLOBAL_IK.read().unwrap()}
+            }
+        };
+        let text = "to be or not to be";
+        let tokens =
+            lock_guard.tokenize_with_stop_words(text, TokenMode::SEARCH, &StopWords::Disabled);
+        let token_texts: Vec<&str> = tokens.iter().map(|t| t.lexeme_text()).collect();
+        assert_eq!(vec!["to", "be", "or", "not", "to", "be"], token_texts);
+
+        let stop_words = StopWords::custom(&["To", "or"]);
+        let tokens = lock_guard.tokenize_with_stop_words(text, TokenMode::SEARCH, &stop_words);
+        let token_texts: Vec<(&str, usize)> = tokens
+            .iter()
+            .map(|t| (t.lexeme_text(), t.position_increment()))
+            .collect();
+        assert_eq!(vec![("be", 2), ("not", 2), ("be", 2)], token_texts);
+    }
+
+    #[test]
+    fn test_position() {
+        let lock_guard = {
+            cfg_if::cfg_if! {
+                if #[cfg(feature="use-parking-lot")] {GLOBAL_IK.read()}
+                else /*#cfg(feature="use-std-sync")*/ {GLOBAL_IK.read().unwrap()}
+            }
+        };
+        let tokens = lock_guard.tokenize("中华人民共和国", TokenMode::INDEX);
+        let positions: Vec<(&str, usize, usize)> = tokens
+            .iter()
+            .map(|t| (t.lexeme_text(), t.position_increment(), t.position_length()))
+            .collect();
+        assert_eq!(
+            vec![
+                ("中华人民共和国", 1, 5),
+                ("中华人民", 0, 3),
+                ("中华", 0, 2),
+                ("华人", 1, 2),
+                ("人民共和国", 1, 3),
+                ("人民", 0, 1),
+                ("共和国", 1, 2),
+                ("共和", 0, 1),
+                ("国", 1, 1)
+            ],
+            positions
+        );
+        let tokens = lock_guard.tokenize("我是中国人", TokenMode::SEARCH);
+        assert!(tokens
+            .iter()
+            .all(|t| t.position_increment() == 1 && t.position_length() == 1));
+    }
+
     // SEARCH Mode
     fn assert_search_token(text: &str, expect: Vec<&str>) {
         let lock_guard = {